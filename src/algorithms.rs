@@ -0,0 +1,168 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap}, hash::Hash, iter, ops::{Add, Sub}};
+
+use crate::spatial::{direction::Cardinal, Matrix, Point};
+
+/// Finds the cost of the cheapest path from `start` to `goal` through a grid,
+/// moving in cardinal directions using Dijkstra's algorithm
+/// 
+/// Entering a cell costs `cost(cell)`, the starting cell is free.
+/// Returns [`None`] if `goal` is not reachable
+pub fn dijkstra_grid<T, F>(matrix: &Matrix<T>, start: Point<usize>, goal: Point<usize>, cost: F) -> Option<u64> where
+    F: Fn(&T) -> u64
+{
+    matrix.get(start)?;
+
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+    while let Some(Reverse((distance, point))) = queue.pop() {
+        if point == goal { return Some(distance); }
+        if distances.get(&point).is_some_and(|&best| best < distance) { continue; }
+
+        for neighbour in point.neighbours::<Cardinal>() {
+            let Some(cell) = matrix.get(neighbour) else { continue };
+            let distance = distance + cost(cell);
+
+            if distances.get(&neighbour).is_none_or(|&best| distance < best) {
+                distances.insert(neighbour, distance);
+                queue.push(Reverse((distance, neighbour)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest path from `start` to `goal` using the A* algorithm
+/// 
+/// `neighbours` produces the nodes reachable from a node, and `cost` the cost
+/// of moving between two adjacent nodes. `heuristic` estimates the remaining cost
+/// from a node to `goal`, it must never overestimate for the result to be optimal.
+/// 
+/// Returns the path including both `start` and `goal` along with its total cost,
+/// or [`None`] if `goal` is not reachable
+pub fn a_star<N, I, FN, FC, FH>(
+    start: N,
+    goal: &N,
+    mut neighbours: FN,
+    mut cost: FC,
+    mut heuristic: FH
+) -> Option<(Vec<N>, u64)> where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item=N>,
+    FN: FnMut(&N) -> I,
+    FC: FnMut(&N, &N) -> u64,
+    FH: FnMut(&N) -> u64
+{
+    // Ties are broken in favour of the node furthest along its path
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), Reverse(0), 0))]);
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![(start, 0, None)];
+
+    while let Some(Reverse((_, Reverse(distance), index))) = queue.pop() {
+        let (node, best, _) = &nodes[index];
+        if distance > *best { continue; }
+
+        if node == goal {
+            let mut path: Vec<N> = iter::successors(Some(index), |&index| nodes[index].2)
+                .map(|index| nodes[index].0.clone())
+                .collect();
+
+            path.reverse();
+            return Some((path, distance));
+        }
+
+        let node = node.clone();
+        for neighbour in neighbours(&node) {
+            let distance = distance + cost(&node, &neighbour);
+            let estimate = distance + heuristic(&neighbour);
+
+            let neighbour_index = match indices.get(&neighbour) {
+                Some(&other) if nodes[other].1 <= distance => continue,
+                Some(&other) => {
+                    nodes[other] = (neighbour, distance, Some(index));
+                    other
+                },
+                None => {
+                    indices.insert(neighbour.clone(), nodes.len());
+                    nodes.push((neighbour, distance, Some(index)));
+                    nodes.len() - 1
+                }
+            };
+
+            queue.push(Reverse((estimate, Reverse(distance), neighbour_index)));
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest path between two points using [`a_star`],
+/// with the manhattan distance to `goal` as the heuristic
+pub fn a_star_manhattan<T, I, FN, FC>(
+    start: Point<T>,
+    goal: Point<T>,
+    neighbours: FN,
+    cost: FC
+) -> Option<(Vec<Point<T>>, u64)> where
+    T: Hash + Ord + Copy + Add<Output=T> + Sub<Output=T> + TryInto<u64>,
+    I: IntoIterator<Item=Point<T>>,
+    FN: FnMut(&Point<T>) -> I,
+    FC: FnMut(&Point<T>, &Point<T>) -> u64
+{
+    a_star(start, &goal, neighbours, cost, |point| {
+        point.manhattan_distance(goal).try_into().unwrap_or(0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::spatial::Area;
+    use super::*;
+
+    #[test]
+    fn dijkstra_weighted_grid() {
+        let matrix = Matrix::from_row_iter([
+            [1, 1, 6, 3],
+            [1, 3, 8, 1],
+            [2, 1, 3, 6],
+            [3, 6, 9, 4]
+        ]).unwrap();
+
+        assert_eq!(Some(17), dijkstra_grid(&matrix, Point::zero(), Point::new(3, 3), |&cost| cost));
+        assert_eq!(Some(0), dijkstra_grid(&matrix, Point::zero(), Point::zero(), |&cost| cost));
+        assert_eq!(Some(6), dijkstra_grid(&matrix, Point::zero(), Point::new(3, 3), |_| 1));
+        assert_eq!(None, dijkstra_grid(&matrix, Point::zero(), Point::new(4, 0), |&cost| cost));
+    }
+
+    #[test]
+    fn a_star_explores_less_than_dijkstra() {
+        let area = Area::<usize>::from_dimensions(10, 10);
+        let goal = Point::new(9, 9);
+
+        let explored = Cell::new(0);
+        let neighbours = |point: &Point<usize>| {
+            explored.set(explored.get() + 1);
+            point
+                .neighbours::<Cardinal>()
+                .filter(|&neighbour| area.contains(neighbour))
+                .collect::<Vec<_>>()
+        };
+
+        let (path, cost) = a_star_manhattan(Point::zero(), goal, neighbours, |_, _| 1).unwrap();
+        let a_star_explored = explored.replace(0);
+
+        assert_eq!(18, cost);
+        assert_eq!(19, path.len());
+        assert_eq!(Some(&Point::zero()), path.first());
+        assert_eq!(Some(&goal), path.last());
+
+        let (_, dijkstra_cost) = a_star(Point::zero(), &goal, neighbours, |_, _| 1, |_| 0).unwrap();
+        assert_eq!(cost, dijkstra_cost);
+        assert!(a_star_explored < explored.get());
+
+        assert_eq!(None, a_star(0, &5, |_| [], |_, _| 1, |_| 0));
+    }
+}
\ No newline at end of file
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::spatial::Point;
+
 /// An error for when a puzzle has no valid solutions for a given input
 #[derive(Debug, Error)]
 #[error("The puzzle has no valid solution for the given input")]
@@ -13,4 +15,26 @@ pub struct MultipleSolutions;
 /// An error for when the input to a puzzle is empty
 #[derive(Debug, Error)]
 #[error("The puzzle input is empty")]
-pub struct NoInput;
\ No newline at end of file
+pub struct NoInput;
+
+/// An error for when a point lies outside of a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Error)]
+#[error("The point {point} is out of bounds for a grid with {rows} rows and {cols} columns")]
+pub struct OutOfBounds {
+    pub point: Point<usize>,
+    pub rows: usize,
+    pub cols: usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn out_of_bounds() {
+        let error = OutOfBounds { point: Point::new(3, 1), rows: 2, cols: 3 };
+
+        assert_eq!("The point (3, 1) is out of bounds for a grid with 2 rows and 3 columns", error.to_string());
+        assert!(matches!(error, OutOfBounds { point: Point { x: 3, .. }, cols: 3, .. }));
+    }
+}
\ No newline at end of file
@@ -1,3 +1,6 @@
+use std::{cmp::{self, Reverse}, collections::{HashMap, HashSet}, hash::Hash, iter};
+
+use num_traits::{CheckedAdd, CheckedMul, One, Zero};
 use thiserror::Error;
 
 use crate::spatial::Point;
@@ -19,6 +22,230 @@ pub trait ExtraIter: Iterator + Sized {
                 Some(_) => Err(SingleError::More)
             })
     }
+
+    /// Assert that the iterator yields a single element and return it,
+    /// mapping the failure into a custom error using `f`
+    fn single_or_else<E, F>(self, f: F) -> Result<Self::Item, E> where
+        F: FnOnce(SingleError) -> E
+    {
+        self.single().map_err(f)
+    }
+
+    /// Counts the amount of times each element occurs
+    fn counts(self) -> HashMap<Self::Item, usize> where
+        Self::Item: Hash + Eq
+    {
+        self.fold(HashMap::new(), |mut counts, item| {
+            *counts.entry(item).or_default() += 1;
+            counts
+        })
+    }
+
+    /// Finds the element that occurs most often and how many times it occurs,
+    /// ties are broken in favour of the element that occurred first
+    fn most_common(self) -> Option<(Self::Item, usize)> where
+        Self::Item: Hash + Eq
+    {
+        let mut counts: HashMap<Self::Item, (usize, usize)> = HashMap::new();
+        for (index, item) in self.enumerate() {
+            counts.entry(item).or_insert((index, 0)).1 += 1;
+        }
+
+        counts
+            .into_iter()
+            .max_by_key(|&(_, (first, count))| (count, Reverse(first)))
+            .map(|(item, (_, count))| (item, count))
+    }
+
+    /// Finds the first element that has been seen before,
+    /// stops consuming the iterator as soon as it is found
+    fn first_duplicate(mut self) -> Option<Self::Item> where
+        Self::Item: Hash + Eq + Clone
+    {
+        let mut seen = HashSet::new();
+        self.find(|item| !seen.insert(item.clone()))
+    }
+
+    /// Creates an iterator that yields the largest element seen so far
+    fn running_max(self) -> impl Iterator<Item=Self::Item> where
+        Self::Item: Ord + Clone
+    {
+        self.scan(None, |max, item| {
+            let current = match max.take() {
+                Some(max) => cmp::max(max, item),
+                None => item
+            };
+
+            *max = Some(current.clone());
+            Some(current)
+        })
+    }
+
+    /// Creates an iterator that yields the smallest element seen so far
+    fn running_min(self) -> impl Iterator<Item=Self::Item> where
+        Self::Item: Ord + Clone
+    {
+        self.scan(None, |min, item| {
+            let current = match min.take() {
+                Some(min) => cmp::min(min, item),
+                None => item
+            };
+
+            *min = Some(current.clone());
+            Some(current)
+        })
+    }
+
+    /// Creates an iterator over consecutive overlapping pairs of elements
+    fn pairwise(self) -> impl Iterator<Item=(Self::Item, Self::Item)> where
+        Self::Item: Clone
+    {
+        itertools::Itertools::tuple_windows(self)
+    }
+
+    /// Groups runs of adjacent elements that share the same `key`,
+    /// a new group is started whenever the key changes
+    fn group_adjacent<K, F>(self, key: F) -> impl Iterator<Item=Vec<Self::Item>> where
+        K: Eq,
+        F: Fn(&Self::Item) -> K
+    {
+        let mut iter = self.peekable();
+
+        iter::from_fn(move || {
+            let first = iter.next()?;
+            let group_key = key(&first);
+            let mut group = vec![first];
+
+            while let Some(item) = iter.next_if(|item| key(item) == group_key) {
+                group.push(item);
+            }
+
+            Some(group)
+        })
+    }
+
+    /// Sums the elements of the iterator,
+    /// returning [`None`] if the sum overflows
+    fn checked_sum(mut self) -> Option<Self::Item> where
+        Self::Item: CheckedAdd + Zero
+    {
+        self.try_fold(Self::Item::zero(), |sum, item| sum.checked_add(&item))
+    }
+
+    /// Multiplies the elements of the iterator,
+    /// returning [`None`] if the product overflows
+    fn checked_product(mut self) -> Option<Self::Item> where
+        Self::Item: CheckedMul + One
+    {
+        self.try_fold(Self::Item::one(), |product, item| product.checked_mul(&item))
+    }
+
+    /// Finds both the smallest and the largest element in a single pass
+    /// 
+    /// Like [`Iterator::min`] and [`Iterator::max`], ties resolve to
+    /// the first minimum and the last maximum
+    fn min_max(mut self) -> Option<(Self::Item, Self::Item)> where
+        Self::Item: Ord + Clone
+    {
+        let first = self.next()?;
+
+        Some(self.fold((first.clone(), first), |(min, max), item| {
+            if item < min { (item, max) }
+            else if item >= max { (min, item) }
+            else { (min, max) }
+        }))
+    }
+
+    /// Finds the index and value of the largest element,
+    /// ties are broken in favour of the element that occurred first
+    fn argmax(self) -> Option<(usize, Self::Item)> where
+        Self::Item: Ord
+    {
+        self
+            .enumerate()
+            .reduce(|best, current| if current.1 > best.1 { current } else { best })
+    }
+
+    /// Finds the index and value of the smallest element,
+    /// ties are broken in favour of the element that occurred first
+    fn argmin(self) -> Option<(usize, Self::Item)> where
+        Self::Item: Ord
+    {
+        self
+            .enumerate()
+            .reduce(|best, current| if current.1 < best.1 { current } else { best })
+    }
+
+    /// Collects the iterator into an array,
+    /// fails if the iterator does not yield _exactly_ `N` elements
+    fn collect_array<const N: usize>(mut self) -> Result<[Self::Item; N], ArrayLengthError> {
+        let items: Vec<Self::Item> = self.by_ref().take(N).collect();
+        if self.next().is_some() { return Err(ArrayLengthError::TooMany); }
+
+        items
+            .try_into()
+            .map_err(|_| ArrayLengthError::TooFew)
+    }
+
+    /// Collapses runs of equal adjacent elements into
+    /// the element and the length of its run
+    fn dedup_count(self) -> impl Iterator<Item=(Self::Item, usize)> where
+        Self::Item: PartialEq
+    {
+        let mut iter = self.peekable();
+
+        iter::from_fn(move || {
+            let item = iter.next()?;
+            let mut count = 1;
+
+            while iter.next_if_eq(&item).is_some() {
+                count += 1;
+            }
+
+            Some((item, count))
+        })
+    }
+
+    /// Creates an iterator that yields elements while `predicate` holds,
+    /// including the first element for which it does not
+    fn take_while_inclusive<P>(mut self, mut predicate: P) -> impl Iterator<Item=Self::Item> where
+        P: FnMut(&Self::Item) -> bool
+    {
+        let mut done = false;
+
+        iter::from_fn(move || {
+            if done { return None; }
+
+            let item = self.next()?;
+            done = !predicate(&item);
+            Some(item)
+        })
+    }
+
+    /// Creates an iterator over consecutive non-overlapping arrays of `N` elements,
+    /// a trailing chunk with fewer than `N` elements is dropped
+    /// 
+    /// Panics if `N` is zero
+    fn arrays<const N: usize>(mut self) -> impl Iterator<Item=[Self::Item; N]> {
+        assert!(N > 0, "Arrays must contain at least one element");
+
+        iter::from_fn(move || self
+            .by_ref()
+            .take(N)
+            .collect::<Vec<_>>()
+            .try_into()
+            .ok()
+        )
+    }
+
+    /// Creates an iterator over the indices of all elements matching `predicate`
+    fn positions<P>(self, mut predicate: P) -> impl Iterator<Item=usize> where
+        P: FnMut(&Self::Item) -> bool
+    {
+        self
+            .enumerate()
+            .filter_map(move |(index, item)| predicate(&item).then_some(index))
+    }
 }
 
 impl<I: Iterator + Sized> ExtraIter for I {}
@@ -37,11 +264,33 @@ pub enum SingleError {
     #[error("Iterator yielded more than one element")] More
 }
 
+/// An error for when an iterator did not yield the amount of elements
+/// required to fill an array
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
+pub enum ArrayLengthError {
+    #[error("Iterator yielded too few elements")] TooFew,
+    #[error("Iterator yielded too many elements")] TooMany
+}
+
 pub trait Enumerate2D: Sized + Iterator {
     type Item;
     type IntoIter: Iterator<Item=(Point<usize>, <Self as Enumerate2D>::Item)>;
 
     fn enumerate2d(self) -> Self::IntoIter;
+
+    /// Like [`Enumerate2D::enumerate2d`], but collects the rows up front to count the elements,
+    /// so the resulting iterator knows its exact length
+    fn enumerate2d_exact(self) -> ExactEnumerate2DIter<<<Self as Iterator>::Item as IntoIterator>::IntoIter> where
+        <Self as Iterator>::Item: IntoIterator<IntoIter: ExactSizeIterator>
+    {
+        let rows: Vec<_> = self.map(IntoIterator::into_iter).collect();
+        let remaining = rows.iter().map(ExactSizeIterator::len).sum();
+
+        ExactEnumerate2DIter {
+            inner: rows.into_iter().enumerate2d(),
+            remaining
+        }
+    }
 }
 
 impl<I> Enumerate2D for I where
@@ -49,19 +298,161 @@ impl<I> Enumerate2D for I where
     I::Item: IntoIterator
 {
     type Item = <I::Item as IntoIterator>::Item;
-    type IntoIter = impl Iterator<Item=(Point<usize>, <Self as Enumerate2D>::Item)>;
+    type IntoIter = Enumerate2DIter<I>;
 
     fn enumerate2d(self) -> Self::IntoIter {
-        self
-            .enumerate()
-            .flat_map(|(y, row)| row
-                .into_iter()
-                .enumerate()
-                .map(move |(x, item)| (Point { x, y }, item))
-            )
+        Enumerate2DIter {
+            rows: self.enumerate(),
+            front: None,
+            back: None
+        }
+    }
+}
+
+type EnumeratedRow<I> = (usize, iter::Enumerate<<<I as Iterator>::Item as IntoIterator>::IntoIter>);
+
+/// Iterates over the elements of a 2D iterator along with their position
+/// 
+/// The iteration happens left-to-right, top-to-bottom
+pub struct Enumerate2DIter<I> where
+    I: Iterator,
+    I::Item: IntoIterator
+{
+    rows: iter::Enumerate<I>,
+    front: Option<EnumeratedRow<I>>,
+    back: Option<EnumeratedRow<I>>
+}
+
+impl<I> Iterator for Enumerate2DIter<I> where
+    I: Iterator,
+    I::Item: IntoIterator
+{
+    type Item = (Point<usize>, <I::Item as IntoIterator>::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((y, row)) = &mut self.front {
+                if let Some((x, item)) = row.next() {
+                    return Some((Point { x, y: *y }, item));
+                }
+
+                self.front = None;
+            }
+
+            let Some((y, row)) = self.rows.next() else { break };
+            self.front = Some((y, row.into_iter().enumerate()));
+        }
+
+        let (y, row) = self.back.as_mut()?;
+        let Some((x, item)) = row.next() else {
+            self.back = None;
+            return None;
+        };
+
+        Some((Point { x, y: *y }, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let row_hint = |row: &Option<EnumeratedRow<I>>| row
+            .as_ref()
+            .map_or((0, Some(0)), |(_, row)| row.size_hint());
+
+        let (front_lower, front_upper) = row_hint(&self.front);
+        let (back_lower, back_upper) = row_hint(&self.back);
+
+        let upper = match (self.rows.size_hint(), front_upper, back_upper) {
+            ((_, Some(0)), Some(front), Some(back)) => front.checked_add(back),
+            _ => None
+        };
+
+        (front_lower.saturating_add(back_lower), upper)
     }
 }
 
+impl<I> DoubleEndedIterator for Enumerate2DIter<I> where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::IntoIter: DoubleEndedIterator + ExactSizeIterator
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((y, row)) = &mut self.back {
+                if let Some((x, item)) = row.next_back() {
+                    return Some((Point { x, y: *y }, item));
+                }
+
+                self.back = None;
+            }
+
+            let Some((y, row)) = self.rows.next_back() else { break };
+            self.back = Some((y, row.into_iter().enumerate()));
+        }
+
+        let (y, row) = self.front.as_mut()?;
+        let Some((x, item)) = row.next_back() else {
+            self.front = None;
+            return None;
+        };
+
+        Some((Point { x, y: *y }, item))
+    }
+}
+
+/// Iterates over the elements of a 2D iterator along with their position,
+/// keeping track of the number of remaining elements
+/// 
+/// The iteration happens left-to-right, top-to-bottom
+pub struct ExactEnumerate2DIter<R: Iterator> {
+    inner: Enumerate2DIter<std::vec::IntoIter<R>>,
+    remaining: usize
+}
+
+impl<R: Iterator> Iterator for ExactEnumerate2DIter<R> {
+    type Item = (Point<usize>, R::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<R> DoubleEndedIterator for ExactEnumerate2DIter<R> where
+    R: DoubleEndedIterator + ExactSizeIterator
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next_back()?;
+        self.remaining -= 1;
+        Some(item)
+    }
+}
+
+impl<R: Iterator> ExactSizeIterator for ExactEnumerate2DIter<R> {}
+
+/// Creates an iterator over every [`Point`] whose `x` component is in `xs`
+/// and `y` component is in `ys`
+/// 
+/// The points are visited left-to-right, top-to-bottom
+pub fn cartesian_product<T, X, Y>(xs: X, ys: Y) -> impl Iterator<Item=Point<T>> where
+    T: Clone,
+    X: IntoIterator<Item=T>,
+    X::IntoIter: Clone,
+    Y: IntoIterator<Item=T>
+{
+    let xs = xs.into_iter();
+
+    ys
+        .into_iter()
+        .flat_map(move |y| xs
+            .clone()
+            .map(move |x| Point { x, y: y.clone() })
+        )
+}
+
 #[cfg(test)]
 mod tests {
     use std::iter::{empty, once};
@@ -77,6 +468,142 @@ mod tests {
         assert_eq!(Err(SingleError::More), [1, 2].into_iter().single());
     }
 
+    #[test]
+    fn extra_iter_single_or_else() {
+        let describe = |err: SingleError| err.to_string();
+
+        assert_eq!(Ok(1), once(1).single_or_else(describe));
+        assert_eq!(Err(String::from("Iterator yielded no elements")), empty::<()>().single_or_else(describe));
+        assert_eq!(Err(SingleError::More), [1, 2].into_iter().single_or_else(|err| err));
+    }
+
+    #[test]
+    fn extra_iter_counts() {
+        assert_eq!(
+            HashMap::from([('a', 2), ('b', 3), ('c', 1)]),
+            "aabbbc".chars().counts()
+        );
+    }
+
+    #[test]
+    fn extra_iter_most_common() {
+        assert_eq!(Some(('b', 3)), "aabbbc".chars().most_common());
+        assert_eq!(Some(('c', 2)), "cabbca".chars().most_common());
+        assert_eq!(None, empty::<()>().most_common());
+    }
+
+    #[test]
+    fn extra_iter_first_duplicate() {
+        assert_eq!(Some(1), [3, 1, 4, 1, 5].into_iter().first_duplicate());
+        assert_eq!(None, [1, 2, 3].into_iter().first_duplicate());
+    }
+
+    #[test]
+    fn extra_iter_running_extremes() {
+        assert_equal([3, 3, 4, 4, 5], [3, 1, 4, 1, 5].into_iter().running_max());
+        assert_equal([3, 1, 1, 1, 1], [3, 1, 4, 1, 5].into_iter().running_min());
+    }
+
+    #[test]
+    fn extra_iter_pairwise() {
+        assert_equal([(1, 3), (3, 2)], [1, 3, 2].into_iter().pairwise());
+        assert_eq!(None, once(1).pairwise().next());
+    }
+
+    #[test]
+    fn extra_iter_group_adjacent() {
+        assert_equal(
+            [vec![1, 1], vec![2], vec![3, 3, 3]],
+            [1, 1, 2, 3, 3, 3].into_iter().group_adjacent(|&x| x)
+        );
+
+        assert_equal(
+            [vec![2, 4], vec![1], vec![6]],
+            [2, 4, 1, 6].into_iter().group_adjacent(|x| x % 2)
+        );
+    }
+
+    #[test]
+    fn extra_iter_checked_sum() {
+        assert_eq!(Some(255), [200u8, 50, 5].into_iter().checked_sum());
+        assert_eq!(None, [200u8, 50, 6].into_iter().checked_sum());
+        assert_eq!(Some(0), empty::<u8>().checked_sum());
+    }
+
+    #[test]
+    fn extra_iter_checked_product() {
+        assert_eq!(Some(240), [2u8, 8, 15].into_iter().checked_product());
+        assert_eq!(None, [2u8, 8, 16].into_iter().checked_product());
+        assert_eq!(Some(1), empty::<u8>().checked_product());
+    }
+
+    #[test]
+    fn extra_iter_min_max() {
+        assert_eq!(Some((1, 5)), [3, 1, 4, 1, 5].into_iter().min_max());
+        assert_eq!(Some((7, 7)), once(7).min_max());
+        assert_eq!(None, empty::<u32>().min_max());
+    }
+
+    #[test]
+    fn extra_iter_argmax() {
+        assert_eq!(Some((1, 7)), [3, 7, 2, 7].into_iter().argmax());
+        assert_eq!(None, empty::<u32>().argmax());
+    }
+
+    #[test]
+    fn extra_iter_argmin() {
+        assert_eq!(Some((2, 2)), [3, 7, 2, 7, 2].into_iter().argmin());
+        assert_eq!(None, empty::<u32>().argmin());
+    }
+
+    #[test]
+    fn extra_iter_collect_array() {
+        assert_eq!(Ok([1, 2, 3]), [1, 2, 3].into_iter().collect_array::<3>());
+        assert_eq!(Err(ArrayLengthError::TooFew), [1, 2].into_iter().collect_array::<3>());
+        assert_eq!(Err(ArrayLengthError::TooMany), [1, 2, 3, 4].into_iter().collect_array::<3>());
+        assert_eq!(Ok([]), empty::<u32>().collect_array::<0>());
+    }
+
+    #[test]
+    fn extra_iter_dedup_count() {
+        assert_equal([(1, 2), (2, 1), (1, 1)], [1, 1, 2, 1].into_iter().dedup_count());
+        assert_equal(['a', 'b'].map(|c| (c, 3)), "aaabbb".chars().dedup_count());
+        assert_eq!(0, empty::<u32>().dedup_count().count());
+    }
+
+    #[test]
+    fn extra_iter_take_while_inclusive() {
+        assert_equal([1, 2, 3], [1, 2, 3, 4].into_iter().take_while_inclusive(|x| *x < 3));
+        assert_equal([1, 2], [1, 2].into_iter().take_while_inclusive(|_| true));
+        assert_equal([5], [5, 1].into_iter().take_while_inclusive(|_| false));
+    }
+
+    #[test]
+    fn extra_iter_arrays() {
+        assert_equal([[0, 1], [2, 3], [4, 5]], (0..6).arrays::<2>());
+        assert_equal([[0, 1, 2]], (0..5).arrays::<3>());
+        assert_eq!(0, (0..2).arrays::<3>().count());
+    }
+
+    #[test]
+    fn extra_iter_positions() {
+        assert_equal([1, 3], [0, 1, 0, 1].into_iter().positions(|x| *x == 1));
+        assert_eq!(0, [0, 0].into_iter().positions(|x| *x == 1).count());
+    }
+
+    #[test]
+    fn cartesian_product() {
+        assert_equal(
+            [Point::new(0, 0), Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)],
+            super::cartesian_product(0..2, 0..2)
+        );
+
+        assert_equal(
+            [Point::new(-1, 5), Point::new(1, 5)],
+            super::cartesian_product([-1, 1], 5..=5)
+        );
+    }
+
     #[test]
     fn enumerate2d() {
         assert_equal(
@@ -87,4 +614,62 @@ mod tests {
             [[1, 2], [3, 4]].into_iter().enumerate2d()
         );
     }
+
+    #[test]
+    fn enumerate2d_size_hint() {
+        let grid = [vec![1, 2, 3], vec![4], vec![5, 6]];
+        let mut iter = grid.iter().enumerate2d();
+        let mut remaining = 6;
+
+        loop {
+            let (lower, upper) = iter.size_hint();
+            assert!(lower <= remaining && upper.is_none_or(|upper| upper >= remaining));
+
+            if iter.next_back().or_else(|| iter.next()).is_none() { break; }
+            remaining -= 1;
+        }
+
+        let mut iter = grid.iter().enumerate2d();
+        iter.next();
+        assert_eq!((2, None), iter.size_hint());
+        iter.next_back();
+        assert_eq!((3, None), iter.size_hint());
+
+        let mut iter = once([1, 2, 3]).enumerate2d();
+        iter.next();
+        assert_eq!((2, Some(2)), iter.size_hint());
+    }
+
+    #[test]
+    fn enumerate2d_exact() {
+        let grid: Vec<Vec<_>> = vec![vec![1, 2, 3], vec![], vec![4]];
+        let mut iter = grid.into_iter().enumerate2d_exact();
+        assert_eq!(4, iter.len());
+        assert_eq!((4, Some(4)), iter.size_hint());
+
+        assert_eq!(Some((Point::new(0, 0), 1)), iter.next());
+        assert_eq!(Some((Point::new(0, 2), 4)), iter.next_back());
+        assert_eq!(2, iter.len());
+
+        assert_equal([(Point::new(2, 0), 3), (Point::new(1, 0), 2)], iter.by_ref().rev());
+        assert_eq!(0, iter.len());
+        assert_eq!(0, empty::<[u8; 2]>().enumerate2d_exact().len());
+    }
+
+    #[test]
+    fn enumerate2d_rev() {
+        assert_equal(
+            [
+                (Point::new(1, 1), 4), (Point::new(0, 1), 3),
+                (Point::new(1, 0), 2), (Point::new(0, 0), 1)
+            ],
+            [[1, 2], [3, 4]].into_iter().enumerate2d().rev()
+        );
+
+        let mut iter = once([1, 2]).enumerate2d();
+        assert_eq!(Some((Point::new(0, 0), 1)), iter.next());
+        assert_eq!(Some((Point::new(1, 0), 2)), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+    }
 }
\ No newline at end of file
@@ -1,12 +1,15 @@
 #![feature(
     cmp_minmax,
-    impl_trait_in_assoc_type
+    substr_range
 )]
 
+extern crate self as yuki;
+
 pub mod parsing;
 pub mod tuples;
 pub mod spatial;
 pub mod iterators;
 pub mod num;
 pub mod math;
-pub mod errors;
\ No newline at end of file
+pub mod errors;
+pub mod algorithms;
\ No newline at end of file
@@ -1,6 +1,8 @@
-use std::ops::{Add, Div, Mul};
+use std::{iter, ops::{Add, Div, Mul}};
 
-use num_traits::One;
+use num_traits::{Euclid, One, PrimInt, Signed};
+
+use crate::spatial::{geometry::cross, Point};
 
 /// Trait for the gaussian sum of contiguous ranges
 pub trait GaussSum {
@@ -20,3 +22,544 @@ impl<T> GaussSum for T where
         self * (self + T::one()) / two
     }
 }
+
+/// Computes the sum of the contiguous range `a..=b` in `O(1)` time
+/// 
+/// Returns `0` if `a > b`, ranges with negative bounds are supported.
+/// Like regular arithmetic this overflows if the result does not fit in `T`
+pub fn gauss_sum_range<T: PrimInt>(a: T, b: T) -> T {
+    if a > b { return T::zero(); }
+
+    // The terms of a range spanning zero cancel out pairwise,
+    // leaving a range on a single side of zero
+    let (a, b) = if a < T::zero() && b > T::zero() {
+        if a + b == T::zero() { return T::zero(); }
+        if a + b > T::zero() { (T::one() - a, b) } else { (a, T::zero() - b - T::one()) }
+    } else {
+        (a, b)
+    };
+
+    // Start from the bound closest to zero, so no intermediate exceeds the result
+    let steps = b - a;
+    let count = steps + T::one();
+    if b <= T::zero() {
+        b * count - triangular(steps)
+    } else {
+        a * count + triangular(steps)
+    }
+}
+
+/// Computes the `k`th triangular number, halving a factor before multiplying
+/// so this only overflows if the result does not fit in `T`
+fn triangular<T: PrimInt>(k: T) -> T {
+    let two = T::one() + T::one();
+    if (k % two).is_zero() {
+        k / two * (k + T::one())
+    } else {
+        k * ((k + T::one()) / two)
+    }
+}
+
+/// Computes the index `k` of the triangular number `n`, such that `k.gauss_sum() == n`
+/// 
+/// Returns [`None`] if `n` is not a triangular number
+pub fn triangular_root<T: PrimInt>(n: T) -> Option<T> {
+    if n < T::zero() { return None; }
+
+    // `k` is the floor of `sqrt(2n)`, which is `2 * sqrt(n / 2)` or one more.
+    // Estimating it this way avoids computing `2n`, which may not fit in `T`
+    let two = T::one() + T::one();
+    let estimate = integer_sqrt(n / two) * two;
+
+    [estimate, estimate + T::one()]
+        .into_iter()
+        .find(|&k| checked_triangular(k) == Some(n))
+}
+
+/// Computes the `k`th triangular number, returns [`None`] if it does not fit in `T`
+fn checked_triangular<T: PrimInt>(k: T) -> Option<T> {
+    let two = T::one() + T::one();
+    let next = k.checked_add(&T::one())?;
+
+    if (k % two).is_zero() {
+        (k / two).checked_mul(&next)
+    } else {
+        k.checked_mul(&(next / two))
+    }
+}
+
+/// Computes the greatest common divisor of `a` and `b`
+/// using the euclidean algorithm
+/// 
+/// The result is never negative, and `gcd(0, 0)` is `0`.
+/// Panics if the result does not fit in `T`, which only happens when
+/// one argument is `T::MIN` and the other is `0` or `T::MIN`
+pub fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    let gcd = signed_gcd(a, b);
+    if gcd < T::zero() {
+        T::zero().checked_sub(&gcd).expect("greatest common divisor does not fit in T")
+    } else {
+        gcd
+    }
+}
+
+/// Computes the greatest common divisor of `a` and `b` up to its sign
+fn signed_gcd<T: PrimInt>(mut a: T, mut b: T) -> T {
+    while !b.is_zero() {
+        // `T::MIN % -1` overflows, even though every number is divisible by `-1`
+        let remainder = if b < T::zero() && (b + T::one()).is_zero() { T::zero() } else { a % b };
+        (a, b) = (b, remainder);
+    }
+
+    a
+}
+
+/// Computes the greatest common divisor `g` of `a` and `b`
+/// along with the Bézout coefficients `x` and `y`, such that `a * x + b * y = g`
+/// 
+/// The returned tuple is `(g, x, y)`
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_remainder, mut remainder) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !remainder.is_zero() {
+        let quotient = old_remainder / remainder;
+        (old_remainder, remainder) = (remainder, old_remainder - quotient * remainder);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_remainder < T::zero() {
+        (-old_remainder, -old_x, -old_y)
+    } else {
+        (old_remainder, old_x, old_y)
+    }
+}
+
+/// Computes the modular multiplicative inverse of `a` modulo `modulus`
+/// 
+/// Returns [`None`] if `a` and `modulus` are not coprime
+pub fn mod_inverse<T: PrimInt + Signed + Euclid>(a: T, modulus: T) -> Option<T> {
+    let (gcd, x, _) = extended_gcd(a, modulus);
+    gcd.is_one().then(|| x.rem_euclid(&modulus))
+}
+
+/// Solves the linear congruence `a * x ≡ b (mod modulus)`
+/// 
+/// Returns the solutions as `x ≡ remainder (mod reduced_modulus)` in the form `(remainder, reduced_modulus)`,
+/// or [`None`] if the congruence has no solutions. Panics if `modulus` is not positive
+pub fn solve_congruence<T: PrimInt + Signed + Euclid>(a: T, b: T, modulus: T) -> Option<(T, T)> {
+    assert!(modulus > T::zero(), "modulus must be positive");
+
+    let (gcd, x, _) = extended_gcd(a, modulus);
+    if !(b % gcd).is_zero() { return None; }
+
+    let reduced_modulus = modulus / gcd;
+    Some(((b / gcd * x).rem_euclid(&reduced_modulus), reduced_modulus))
+}
+
+/// Solves a system of congruences `x ≡ remainder (mod modulus)`
+/// given as `(remainder, modulus)` pairs using the Chinese remainder theorem
+/// 
+/// The moduli do not have to be coprime. Returns the combined `(remainder, modulus)`,
+/// or [`None`] if the system has no solutions.
+/// The combined modulus is the least common multiple of the moduli, which must fit in `T`.
+/// Panics if any modulus is not positive
+pub fn crt<T: PrimInt + Signed + Euclid>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::zero(), T::one()), |(remainder, modulus), &(other_remainder, other_modulus)| {
+            assert!(other_modulus > T::zero(), "modulus must be positive");
+
+            let (gcd, x, _) = extended_gcd(modulus, other_modulus);
+            let difference = other_remainder - remainder;
+            if !(difference % gcd).is_zero() { return None; }
+
+            let step = (difference / gcd * x).rem_euclid(&(other_modulus / gcd));
+            let combined_modulus = modulus / gcd * other_modulus;
+            Some(((remainder + modulus * step).rem_euclid(&combined_modulus), combined_modulus))
+        })
+}
+
+/// Computes the least common multiple of `a` and `b`
+/// 
+/// The result is never negative, and is `0` if either argument is `0`.
+/// Like regular arithmetic this overflows if the result does not fit in `T`
+pub fn lcm<T: PrimInt>(a: T, b: T) -> T {
+    if a.is_zero() || b.is_zero() { return T::zero(); }
+
+    let lcm = a / gcd(a, b) * b;
+    if lcm < T::zero() { T::zero() - lcm } else { lcm }
+}
+
+/// Computes the least common multiple of all the numbers in `iter`,
+/// returns `1` if `iter` is empty
+/// 
+/// Like regular arithmetic this overflows if the result does not fit in `T`
+pub fn lcm_all<T, I>(iter: I) -> T where
+    T: PrimInt,
+    I: IntoIterator<Item=T>
+{
+    iter
+        .into_iter()
+        .fold(T::one(), lcm)
+}
+
+/// Computes the greatest common divisor of all the numbers in `iter`
+/// 
+/// Returns [`None`] if `iter` is empty
+pub fn gcd_all<T, I>(iter: I) -> Option<T> where
+    T: PrimInt,
+    I: IntoIterator<Item=T>
+{
+    iter
+        .into_iter()
+        .reduce(signed_gcd)
+        .map(|n| gcd(n, T::zero()))
+}
+
+/// Computes the floor of the square root of `n` without using floating point arithmetic
+/// 
+/// Panics if `n` is negative
+pub fn integer_sqrt<T: PrimInt>(n: T) -> T {
+    assert!(n >= T::zero(), "cannot take the square root of a negative number");
+    if n < T::one() + T::one() { return n; }
+
+    let two = T::one() + T::one();
+    let mut estimate = n / two;
+    loop {
+        let next = (estimate + n / estimate) / two;
+        if next >= estimate { return estimate; }
+        estimate = next;
+    }
+}
+
+/// Computes `base` raised to the power `exp` modulo `modulus`
+/// using square-and-multiply in `O(log exp)` time
+/// 
+/// The result is in the range `0..modulus`.
+/// Intermediate products are up to `modulus²`, which must fit in `T`
+pub fn mod_pow<T: PrimInt>(base: T, mut exp: T, modulus: T) -> T {
+    assert!(modulus > T::zero(), "modulus must be positive");
+    assert!(exp >= T::zero(), "exponent must not be negative");
+
+    let two = T::one() + T::one();
+    let mut base = (base % modulus + modulus) % modulus;
+    let mut result = T::one() % modulus;
+
+    while exp > T::zero() {
+        if exp % two == T::one() {
+            result = result * base % modulus;
+        }
+
+        base = base * base % modulus;
+        exp = exp / two;
+    }
+
+    result
+}
+
+/// Determines if `n` is prime using trial division in `O(√n)` time
+pub fn is_prime<T: PrimInt>(n: T) -> bool {
+    let two = T::one() + T::one();
+    if n < two { return false; }
+
+    let mut divisor = two;
+    while divisor <= n / divisor {
+        if (n % divisor).is_zero() { return false; }
+        divisor = divisor + T::one();
+    }
+
+    true
+}
+
+/// Computes all the primes up to and including `n`
+/// using the sieve of Eratosthenes
+#[must_use]
+pub fn primes_up_to(n: usize) -> Vec<usize> {
+    let mut composite = vec![false; n + 1];
+    let mut primes = Vec::new();
+
+    for candidate in 2..=n {
+        if composite[candidate] { continue; }
+        primes.push(candidate);
+
+        for multiple in (candidate.saturating_mul(candidate)..=n).step_by(candidate) {
+            composite[multiple] = true;
+        }
+    }
+
+    primes
+}
+
+/// Computes the number of lattice points strictly inside a simple lattice polygon
+/// from its `area` and the number of lattice points on its boundary using Pick's theorem
+#[must_use]
+pub const fn interior_points(area: i64, boundary_points: i64) -> i64 {
+    area - boundary_points / 2 + 1
+}
+
+/// Counts the lattice points on the edges of the polygon with vertices `points`,
+/// the polygon is closed by an edge from the last vertex back to the first
+/// 
+/// Combined with [`interior_points`] this gives the lattice points inside the polygon
+#[must_use]
+pub fn boundary_points(points: &[Point<i64>]) -> i64 {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(&from, &to)| {
+            let delta = to - from;
+            gcd(delta.x, delta.y)
+        })
+        .sum()
+}
+
+/// Computes twice the area of the triangle with corners `a`, `b` and `c`,
+/// which is always an integer for lattice points
+#[must_use]
+pub const fn doubled_triangle_area(a: Point<i64>, b: Point<i64>, c: Point<i64>) -> i64 {
+    cross(a, b, c).abs()
+}
+
+/// Computes the area of the triangle with corners `a`, `b` and `c`
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub const fn triangle_area(a: Point<i64>, b: Point<i64>, c: Point<i64>) -> f64 {
+    doubled_triangle_area(a, b, c) as f64 / 2.0
+}
+
+/// Computes the binomial coefficient `n choose k`,
+/// the number of ways to choose `k` elements out of `n`
+/// 
+/// Returns `0` if `k > n`, panics if the result does not fit in a [`u64`]
+#[must_use]
+pub fn binomial(n: u64, k: u64) -> u64 {
+    if k > n { return 0; }
+
+    let k = k.min(n - k);
+    let result = (0..k).fold(1u128, |result, i| {
+        result * u128::from(n - i) / u128::from(i + 1)
+    });
+
+    u64::try_from(result).expect("binomial coefficient overflowed")
+}
+
+/// Computes `n!`, the product of all integers from `1` to `n`
+/// 
+/// Panics if the result does not fit in a [`u64`], which happens for `n > 20`
+#[must_use]
+pub fn factorial(n: u64) -> u64 {
+    checked_factorial(n).expect("factorial overflowed")
+}
+
+/// Computes `n!`, the product of all integers from `1` to `n`
+/// 
+/// Returns [`None`] if the result does not fit in a [`u64`], which happens for `n > 20`
+#[must_use]
+pub fn checked_factorial(n: u64) -> Option<u64> {
+    (1..=n).try_fold(1u64, u64::checked_mul)
+}
+
+/// Creates an iterator over the triangular numbers `0, 1, 3, 6, 10, ...`,
+/// the iterator ends once the next number would not fit in `T`
+pub fn triangular_numbers<T: PrimInt>() -> impl Iterator<Item=T> {
+    iter::successors(Some((T::zero(), T::zero())), |&(number, index)| {
+        let index = index + T::one();
+        Some((number.checked_add(&index)?, index))
+    })
+    .map(|(number, _)| number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greatest_common_divisor() {
+        assert_eq!(6, gcd(12, 18));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(0, gcd(0, 0));
+        assert_eq!(6, gcd(12, -18));
+        assert_eq!(1u64, gcd(17, 5));
+
+        assert_eq!(2, gcd(i32::MIN, 6));
+        assert_eq!(1, gcd(i32::MIN, -1));
+        assert_eq!(1 << 30, gcd(i32::MIN / 2, i32::MIN));
+    }
+
+    #[test]
+    #[should_panic = "does not fit"]
+    fn gcd_of_min_and_zero() {
+        gcd(i32::MIN, 0);
+    }
+
+    #[test]
+    fn least_common_multiple() {
+        assert_eq!(12, lcm(4, 6));
+        assert_eq!(0, lcm(0, 6));
+        assert_eq!(12, lcm(-4, 6));
+        assert_eq!(24, lcm_all([4, 6, 8]));
+        assert_eq!(1, lcm_all::<u32, _>([]));
+    }
+
+    #[test]
+    fn greatest_common_divisor_all() {
+        assert_eq!(Some(6), gcd_all([12, 18, 24]));
+        assert_eq!(Some(5), gcd_all([-5]));
+        assert_eq!(Some(2), gcd_all([i8::MIN, i8::MIN, 6]));
+        assert_eq!(None, gcd_all::<u32, _>([]));
+    }
+
+    #[test]
+    fn square_root() {
+        assert_eq!(4, integer_sqrt(16));
+        assert_eq!(3, integer_sqrt(15));
+        assert_eq!(0, integer_sqrt(0));
+        assert_eq!(1, integer_sqrt(3));
+        assert_eq!(u64::from(u32::MAX), integer_sqrt(u64::MAX));
+        assert_eq!(3_037_000_499, integer_sqrt(i64::MAX));
+    }
+
+    #[test]
+    fn modular_exponentiation() {
+        assert_eq!(24, mod_pow(2, 10, 1000));
+        assert_eq!(1, mod_pow(7, 0, 13));
+        assert_eq!(0, mod_pow(7, 0, 1));
+        assert_eq!(4, mod_pow(-2, 3, 6));
+        assert_eq!(445, mod_pow(4u64, 13, 497));
+    }
+
+    #[test]
+    fn primes() {
+        assert!(is_prime(2));
+        assert!(is_prime(13));
+        assert!(is_prime(7919u64));
+        assert!(!is_prime(1));
+        assert!(!is_prime(0));
+        assert!(!is_prime(-7));
+        assert!(!is_prime(49));
+
+        assert_eq!(vec![2, 3, 5, 7], primes_up_to(10));
+        assert_eq!(vec![2, 3, 5, 7, 11], primes_up_to(11));
+        assert!(primes_up_to(1).is_empty());
+    }
+
+    #[test]
+    fn chinese_remainder_theorem() {
+        assert_eq!((2, -1, 1), extended_gcd(4, 6));
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(None, mod_inverse(2, 4));
+
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((10, 12)), crt(&[(2, 4), (4, 6)]));
+        assert_eq!(None, crt(&[(1, 4), (2, 6)]));
+        assert_eq!(Some((0, 1)), crt::<i64>(&[]));
+    }
+
+    #[test]
+    #[should_panic = "modulus must be positive"]
+    fn chinese_remainder_theorem_zero_modulus() {
+        crt(&[(2, 3), (0, 0)]);
+    }
+
+    #[test]
+    fn linear_congruence() {
+        assert_eq!(Some((4, 7)), solve_congruence(3, 5, 7));
+        assert_eq!(Some((2, 5)), solve_congruence(4, 8, 10));
+        assert_eq!(Some((0, 3)), solve_congruence(-6, 9, 9));
+        assert_eq!(None, solve_congruence(4, 3, 10));
+    }
+
+    #[test]
+    #[should_panic = "modulus must be positive"]
+    fn linear_congruence_zero_modulus() {
+        solve_congruence(2, 4, 0);
+    }
+
+    #[test]
+    fn picks_theorem() {
+        // The square spanning (0, 0) to (3, 3)
+        assert_eq!(4, interior_points(9, 12));
+        // The triangle (0, 0), (4, 0), (0, 4)
+        assert_eq!(3, interior_points(8, 12));
+    }
+
+    #[test]
+    fn polygon_boundary_points() {
+        let square = [(0, 0), (1, 0), (1, 1), (0, 1)].map(Point::from);
+        assert_eq!(4, boundary_points(&square));
+
+        let triangle = [(0, 0), (4, 0), (0, 3)].map(Point::from);
+        assert_eq!(8, boundary_points(&triangle));
+        assert_eq!(3, interior_points(6, boundary_points(&triangle)));
+
+        assert_eq!(0, boundary_points(&[]));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn triangle_areas() {
+        let (a, b, c) = (Point::new(0, 0), Point::new(3, 0), Point::new(0, 4));
+
+        assert_eq!(6.0, triangle_area(a, b, c));
+        assert_eq!(6.0, triangle_area(a, c, b));
+        assert_eq!(3, doubled_triangle_area(a, Point::new(1, 1), Point::new(2, -1)));
+        assert_eq!(0, doubled_triangle_area(a, Point::new(1, 1), Point::new(2, 2)));
+    }
+
+    #[test]
+    fn binomial_coefficient() {
+        assert_eq!(10, binomial(5, 2));
+        assert_eq!(1, binomial(5, 0));
+        assert_eq!(1, binomial(5, 5));
+        assert_eq!(0, binomial(2, 5));
+        assert_eq!(40_225_345_056, binomial(40, 15));
+    }
+
+    #[test]
+    fn triangular_roots() {
+        assert_eq!(Some(4), triangular_root(10));
+        assert_eq!(Some(0), triangular_root(0));
+        assert_eq!(Some(1), triangular_root(1));
+        assert_eq!(None, triangular_root(11));
+        assert_eq!(None, triangular_root(-3));
+
+        assert_eq!(Some(92_681), triangular_root(4_294_930_221u32));
+        assert_eq!(None, triangular_root(u32::MAX));
+        assert_eq!(Some(22), triangular_root(253u8));
+        assert_eq!(Some(15), triangular_root(120i8));
+        assert_eq!(None, triangular_root(i8::MAX));
+    }
+
+    #[test]
+    fn gauss_sum_ranges() {
+        assert_eq!(12, gauss_sum_range(3, 5));
+        assert_eq!(15u32, gauss_sum_range(0, 5));
+        assert_eq!(0, gauss_sum_range(5, 3));
+        assert_eq!(-3, gauss_sum_range(-3, 2));
+        assert_eq!(-9, gauss_sum_range(-4, -2));
+        assert_eq!(253u8, gauss_sum_range(0, 22));
+        assert_eq!(200u8, gauss_sum_range(200, 200));
+        assert_eq!(255u8, gauss_sum_range(127, 128));
+        assert_eq!(-126i8, gauss_sum_range(-43, -41));
+        assert_eq!(i8::MIN, gauss_sum_range(i8::MIN, i8::MAX));
+        assert_eq!(0i8, gauss_sum_range(-i8::MAX, i8::MAX));
+    }
+
+    #[test]
+    fn factorials() {
+        assert_eq!(120, factorial(5));
+        assert_eq!(1, factorial(0));
+        assert_eq!(Some(2_432_902_008_176_640_000), checked_factorial(20));
+        assert_eq!(None, checked_factorial(21));
+    }
+
+    #[test]
+    fn triangular_number_sequence() {
+        assert_eq!(vec![0, 1, 3, 6, 10], triangular_numbers::<u32>().take(5).collect::<Vec<_>>());
+        assert_eq!(Some(253), triangular_numbers::<u8>().last());
+        assert!(triangular_numbers::<u64>().take(100).all(|n| triangular_root(n).is_some()));
+    }
+}
\ No newline at end of file
@@ -1,4 +1,6 @@
-use num_traits::Signed;
+use std::{iter, ops::{Add, Mul, Range, Sub}};
+
+use num_traits::{PrimInt, Signed};
 
 /// Trait for adding a signed variant of a number to another
 /// whilst checking for underflows
@@ -11,6 +13,29 @@ pub trait CheckedAddSigned where
     fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self>;
 }
 
+/// Trait for adding a signed variant of a number to another,
+/// saturating at the numeric bounds instead of overflowing
+pub trait SaturatingAddSigned where
+    Self: Sized,
+    Self::Signed: Signed
+{
+    type Signed;
+
+    #[must_use]
+    fn saturating_add_signed(self, rhs: Self::Signed) -> Self;
+}
+
+/// Trait for multiplying a number by a signed variant of it
+/// whilst checking for overflows and underflows
+pub trait CheckedMulSigned where
+    Self: Sized,
+    Self::Signed: Signed
+{
+    type Signed;
+
+    fn checked_mul_signed(self, rhs: Self::Signed) -> Option<Self>;
+}
+
 pub trait AbsDiff where
     Self: Sized
 {
@@ -19,6 +44,234 @@ pub trait AbsDiff where
     fn abs_diff(self, other: Self) -> Self::Unsigned;
 }
 
+/// Trait for iterating over the digits of an integer,
+/// the sign of negative numbers is ignored
+pub trait Digits: Sized {
+    /// Creates an iterator over the digits in base `radix`,
+    /// from least to most significant
+    /// 
+    /// Panics if `radix` is not in the range `2..=256`
+    fn digits_radix_le(self, radix: u32) -> impl Iterator<Item=u8>;
+
+    /// Creates an iterator over the digits in base `radix`,
+    /// from most to least significant
+    /// 
+    /// Panics if `radix` is not in the range `2..=256`
+    fn digits_radix(self, radix: u32) -> impl Iterator<Item=u8> {
+        let mut digits: Vec<u8> = self.digits_radix_le(radix).collect();
+        digits.reverse();
+        digits.into_iter()
+    }
+
+    /// Creates an iterator over the decimal digits,
+    /// from most to least significant
+    fn digits(self) -> impl Iterator<Item=u8> {
+        self.digits_radix(10)
+    }
+
+    /// Creates an iterator over the decimal digits,
+    /// from least to most significant
+    fn digits_le(self) -> impl Iterator<Item=u8> {
+        self.digits_radix_le(10)
+    }
+}
+
+/// Trait for raising a number to a power
+/// whilst checking for overflows
+pub trait CheckedPow: Sized {
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+
+    /// Raises `self` to the power `exp`, wrapping around on overflow
+    /// and returning whether an overflow occurred
+    fn overflowing_pow(self, exp: u32) -> (Self, bool);
+}
+
+/// Trait for inspecting the individual bits of an integer
+pub trait Bits: Sized {
+    /// Creates an iterator over the indices of the set bits,
+    /// from least to most significant
+    fn set_bits(self) -> impl Iterator<Item=u32>;
+
+    /// Creates an iterator over every bit of the integer,
+    /// from least to most significant
+    fn bits(self) -> impl ExactSizeIterator<Item=bool>;
+}
+
+/// Counts the digits of `number` in base `radix`, ignoring the sign
+/// 
+/// Zero is considered to have a single digit
+pub fn count_digits_radix<T: Digits>(number: T, radix: u32) -> u32 {
+    number
+        .digits_radix_le(radix)
+        .fold(0, |count, _| count + 1)
+}
+
+/// Counts the decimal digits of `number`, ignoring the sign
+/// 
+/// Zero is considered to have a single digit
+pub fn count_digits<T: Digits>(number: T) -> u32 {
+    count_digits_radix(number, 10)
+}
+
+/// Computes the floor of the logarithm of `n` in base `base`
+/// 
+/// Returns [`None`] if `n` is not positive, panics if `base` is less than `2`
+pub fn checked_ilog<T: PrimInt>(mut n: T, base: T) -> Option<u32> {
+    assert!(base > T::one(), "base must be at least 2");
+    if n <= T::zero() { return None; }
+
+    let mut log = 0;
+    while n >= base {
+        n = n / base;
+        log += 1;
+    }
+
+    Some(log)
+}
+
+/// Computes the floor of the logarithm of `n` in base `base`
+/// 
+/// Panics if `n` is not positive or `base` is less than `2`
+pub fn ilog<T: PrimInt>(n: T, base: T) -> u32 {
+    checked_ilog(n, base).expect("logarithm of a non-positive number")
+}
+
+/// Builds a number from its digits in base `radix`,
+/// from most to least significant
+/// 
+/// This is the inverse of [`Digits::digits_radix`]. Panics if the resulting number
+/// is not representable by `T`, a digit is not less than `radix`
+/// or `radix` is not in the range `2..=256`
+pub fn from_digits<T, I>(digits: I, radix: u32) -> T where
+    T: PrimInt,
+    I: IntoIterator<Item=u8>
+{
+    checked_from_digits(digits, radix).expect("number built from digits overflowed")
+}
+
+/// Builds a number from its digits in base `radix`,
+/// from most to least significant
+/// 
+/// Returns [`None`] if the resulting number is not representable by `T`.
+/// Panics if a digit is not less than `radix` or `radix` is not in the range `2..=256`
+pub fn checked_from_digits<T, I>(digits: I, radix: u32) -> Option<T> where
+    T: PrimInt,
+    I: IntoIterator<Item=u8>
+{
+    assert!((2..=256).contains(&radix), "radix must be in the range 2..=256");
+    let radix_in_t = T::from(radix);
+
+    digits
+        .into_iter()
+        .try_fold(T::zero(), |number, digit| {
+            assert!(u32::from(digit) < radix, "digit {digit} is out of range for radix {radix}");
+
+            let shifted = if number.is_zero() { number } else { number.checked_mul(&radix_in_t?)? };
+            shifted.checked_add(&T::from(digit)?)
+        })
+}
+
+/// Wraps `value` around into `range`, such that values past the end
+/// continue from the start and values before the start continue from the end
+/// 
+/// Panics if `range` is empty, the length of `range` must fit in `T`
+pub fn wrap<T: PrimInt>(value: T, range: Range<T>) -> T {
+    assert!(!range.is_empty(), "Cannot wrap into an empty range");
+
+    let length = range.end - range.start;
+    let remainder = |n: T| {
+        let remainder = n % length;
+        if remainder < T::zero() { remainder + length } else { remainder }
+    };
+
+    let (value, start) = (remainder(value), remainder(range.start));
+    let offset = if value >= start { value - start } else { length - (start - value) };
+    range.start + offset
+}
+
+/// Trait for the sign of a number, which is `-1`, `0` or `1`
+/// 
+/// Unsigned numbers are never negative, so their sign is either `0` or `1`
+pub trait Signum {
+    #[must_use]
+    fn signum(self) -> Self;
+}
+
+/// Trait for computing the midpoint of two numbers
+/// without overflowing, which `(a + b) / 2` is prone to
+/// 
+/// The result is rounded towards zero
+pub trait Midpoint {
+    #[must_use]
+    fn midpoint(self, other: Self) -> Self;
+}
+
+/// An integer modulo `M`, arithmetic on it is automatically reduced modulo `M`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, derive_more::Display)]
+#[display("{_0}")]
+pub struct Mod<const M: u64>(u64);
+
+impl<const M: u64> Mod<M> {
+    /// Creates a new number by reducing `value` modulo `M`
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        Self(value % M)
+    }
+
+    /// Returns the reduced value in the range `0..M`
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Raises the number to the power `exp` using exponentiation by squaring
+    #[must_use]
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::new(1);
+
+        while exp > 0 {
+            if exp & 1 == 1 { result = result * base; }
+            base = base * base;
+            exp >>= 1;
+        }
+
+        result
+    }
+}
+
+impl<const M: u64> From<u64> for Mod<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> Add for Mod<M> {
+    type Output = Self;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(((u128::from(self.0) + u128::from(rhs.0)) % u128::from(M)) as u64)
+    }
+}
+
+impl<const M: u64> Sub for Mod<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.0 >= rhs.0 { Self(self.0 - rhs.0) } else { Self(M - (rhs.0 - self.0)) }
+    }
+}
+
+impl<const M: u64> Mul for Mod<M> {
+    type Output = Self;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self((u128::from(self.0) * u128::from(rhs.0) % u128::from(M)) as u64)
+    }
+}
+
 macro_rules! impl_num_traits {
     ($unsigned:ty, $signed:ty) => {
         impl CheckedAddSigned for $signed {
@@ -37,6 +290,41 @@ macro_rules! impl_num_traits {
             }
         }
 
+        impl SaturatingAddSigned for $signed {
+            type Signed = $signed;
+
+            fn saturating_add_signed(self, rhs: Self::Signed) -> Self {
+                self.saturating_add(rhs)
+            }
+        }
+
+        impl SaturatingAddSigned for $unsigned {
+            type Signed = $signed;
+
+            fn saturating_add_signed(self, rhs: Self::Signed) -> Self {
+                <$unsigned>::saturating_add_signed(self, rhs)
+            }
+        }
+
+        impl CheckedMulSigned for $signed {
+            type Signed = $signed;
+
+            fn checked_mul_signed(self, rhs: Self::Signed) -> Option<Self> {
+                self.checked_mul(rhs)
+            }
+        }
+
+        impl CheckedMulSigned for $unsigned {
+            type Signed = $signed;
+
+            fn checked_mul_signed(self, rhs: Self::Signed) -> Option<Self> {
+                match <$unsigned>::try_from(rhs) {
+                    Ok(rhs) => self.checked_mul(rhs),
+                    Err(_) => (self == 0).then_some(0)
+                }
+            }
+        }
+
         impl AbsDiff for $signed {
             type Unsigned = $unsigned;
 
@@ -52,6 +340,100 @@ macro_rules! impl_num_traits {
                 self.abs_diff(rhs)
             }
         }
+
+        impl Digits for $signed {
+            fn digits_radix_le(self, radix: u32) -> impl Iterator<Item=u8> {
+                self.unsigned_abs().digits_radix_le(radix)
+            }
+        }
+
+        impl Digits for $unsigned {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+            fn digits_radix_le(self, radix: u32) -> impl Iterator<Item=u8> {
+                assert!((2..=256).contains(&radix), "radix must be in the range 2..=256");
+
+                let radix = u128::from(radix);
+                let mut remaining = Some(self as u128);
+
+                iter::from_fn(move || {
+                    let number = remaining?;
+                    remaining = Some(number / radix).filter(|&rest| rest != 0);
+                    Some((number % radix) as u8)
+                })
+            }
+        }
+
+        impl CheckedPow for $signed {
+            fn checked_pow(self, exp: u32) -> Option<Self> {
+                <$signed>::checked_pow(self, exp)
+            }
+
+            fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                <$signed>::overflowing_pow(self, exp)
+            }
+        }
+
+        impl CheckedPow for $unsigned {
+            fn checked_pow(self, exp: u32) -> Option<Self> {
+                <$unsigned>::checked_pow(self, exp)
+            }
+
+            fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                <$unsigned>::overflowing_pow(self, exp)
+            }
+        }
+
+        impl Bits for $signed {
+            fn set_bits(self) -> impl Iterator<Item=u32> {
+                self.cast_unsigned().set_bits()
+            }
+
+            fn bits(self) -> impl ExactSizeIterator<Item=bool> {
+                self.cast_unsigned().bits()
+            }
+        }
+
+        impl Bits for $unsigned {
+            fn set_bits(self) -> impl Iterator<Item=u32> {
+                let mut remaining = self;
+
+                iter::from_fn(move || {
+                    if remaining == 0 { return None; }
+
+                    let index = remaining.trailing_zeros();
+                    remaining &= remaining - 1;
+                    Some(index)
+                })
+            }
+
+            fn bits(self) -> impl ExactSizeIterator<Item=bool> {
+                (0..<$unsigned>::BITS).map(move |index| self >> index & 1 == 1)
+            }
+        }
+
+        impl Signum for $signed {
+            fn signum(self) -> Self {
+                <$signed>::signum(self)
+            }
+        }
+
+        impl Signum for $unsigned {
+            fn signum(self) -> Self {
+                Self::from(self != 0)
+            }
+        }
+
+        impl Midpoint for $signed {
+            fn midpoint(self, other: Self) -> Self {
+                <$signed>::midpoint(self, other)
+            }
+        }
+
+        impl Midpoint for $unsigned {
+            fn midpoint(self, other: Self) -> Self {
+                <$unsigned>::midpoint(self, other)
+            }
+        }
     }
 }
 
@@ -60,4 +442,159 @@ impl_num_traits!(u16, i16);
 impl_num_traits!(u32, i32);
 impl_num_traits!(u64, i64);
 impl_num_traits!(u128, i128);
-impl_num_traits!(usize, isize);
\ No newline at end of file
+impl_num_traits!(usize, isize);
+
+#[cfg(test)]
+mod tests {
+    use itertools::assert_equal;
+
+    use super::*;
+
+    #[test]
+    fn saturating_add_signed() {
+        assert_eq!(0, 3u32.saturating_add_signed(-100));
+        assert_eq!(u8::MAX, 250u8.saturating_add_signed(10));
+        assert_eq!(7, 10u8.saturating_add_signed(-3));
+        assert_eq!(i8::MIN, (-100i8).saturating_add_signed(-100));
+    }
+
+    #[test]
+    fn checked_mul_signed() {
+        assert_eq!(Some(-120), 40i8.checked_mul_signed(-3));
+        assert_eq!(None, 50i8.checked_mul_signed(3));
+        assert_eq!(Some(250), 50u8.checked_mul_signed(5));
+        assert_eq!(None, 50u8.checked_mul_signed(6));
+        assert_eq!(None, 1u8.checked_mul_signed(-1));
+        assert_eq!(Some(0), 0u8.checked_mul_signed(-1));
+    }
+
+    #[test]
+    fn digits() {
+        assert_equal([1, 2, 3, 4], 1234u32.digits());
+        assert_equal([4, 3, 2, 1], 1234u32.digits_le());
+        assert_equal([0], 0u8.digits());
+        assert_equal([4, 2], (-42i64).digits());
+        assert_equal([1, 1, 0, 1], 13usize.digits_radix(2));
+        assert_equal([15, 15], 255u8.digits_radix(16));
+    }
+
+    #[test]
+    fn checked_pow() {
+        assert_eq!(Some(243), CheckedPow::checked_pow(3u8, 5));
+        assert_eq!(None, CheckedPow::checked_pow(3u8, 6));
+        assert_eq!(Some(-128), CheckedPow::checked_pow(-2i8, 7));
+        assert_eq!(None, CheckedPow::checked_pow(2i8, 7));
+        assert_eq!((217, true), CheckedPow::overflowing_pow(3u8, 6));
+    }
+
+    #[test]
+    fn set_bits() {
+        assert_equal([1, 3], 0b1010u8.set_bits());
+        assert_equal([7], i8::MIN.set_bits());
+        assert_eq!(0, 0u32.set_bits().count());
+        assert_eq!(128, u128::MAX.set_bits().count());
+    }
+
+    #[test]
+    fn bits() {
+        assert_equal([false, true, false, true, false, false, false, false], 0b1010u8.bits());
+        assert_eq!(64, (-1i64).bits().filter(|&bit| bit).count());
+        assert_eq!(16, 0u16.bits().len());
+    }
+
+    #[test]
+    fn count_digits() {
+        assert_eq!(1, super::count_digits(0u32));
+        assert_eq!(3, super::count_digits(999u32));
+        assert_eq!(4, super::count_digits(1000u64));
+        assert_eq!(3, super::count_digits(i8::MIN));
+        assert_eq!(8, count_digits_radix(255u8, 2));
+    }
+
+    #[test]
+    fn from_digits() {
+        assert_eq!(123, super::from_digits::<u32, _>([1, 2, 3], 10));
+        assert_eq!(13, super::from_digits::<i64, _>([1, 1, 0, 1], 2));
+        assert_eq!(0, super::from_digits::<u8, _>([], 10));
+        assert_eq!(9876, super::from_digits::<u16, _>(9876u16.digits(), 10));
+        assert_eq!(200, super::from_digits::<u8, _>(200u8.digits_radix(256), 256));
+    }
+
+    #[test]
+    fn checked_from_digits() {
+        assert_eq!(Some(255), super::checked_from_digits::<u8, _>([2, 5, 5], 10));
+        assert_eq!(None, super::checked_from_digits::<u8, _>([2, 5, 6], 10));
+        assert_eq!(None, super::checked_from_digits::<i8, _>([1, 2, 8], 10));
+        assert_eq!(None, super::checked_from_digits::<i8, _>([200], 256));
+        assert_eq!(Some(3), super::checked_from_digits::<u8, _>([0, 0, 3], 256));
+    }
+
+    #[test]
+    #[should_panic = "out of range"]
+    fn from_digits_out_of_range() {
+        super::from_digits::<u8, _>([1, 12], 10);
+    }
+
+    #[test]
+    fn wrap() {
+        assert_eq!(9, super::wrap(-1, 0..10));
+        assert_eq!(2, super::wrap(12, 0..10));
+        assert_eq!(0, super::wrap(-10, 0..10));
+        assert_eq!(5, super::wrap(5, 0..10));
+        assert_eq!(4, super::wrap(1u8, 3..6));
+        assert_eq!(-2, super::wrap(-7, -2..3));
+        assert_eq!(2, super::wrap(-8, -2..3));
+
+        assert_eq!(2, super::wrap(i32::MIN, 0..10));
+        assert_eq!(7, super::wrap(i32::MAX, 0..10));
+        assert_eq!(2, super::wrap(i32::MIN, -5..5));
+        assert_eq!(-3, super::wrap(i32::MAX, -5..5));
+        assert_eq!(5, super::wrap(u8::MAX, 0..10));
+        assert_eq!(250, super::wrap(0u8, 250..255));
+        assert_eq!(250, super::wrap(u8::MAX, 250..255));
+        assert_eq!(-1, super::wrap(i8::MIN, -1..i8::MAX - 1));
+        assert_eq!(0, super::wrap(i8::MAX, -1..i8::MAX - 1));
+    }
+
+    #[test]
+    fn ilog() {
+        assert_eq!(2, super::ilog(100, 10));
+        assert_eq!(3, super::ilog(8u8, 2));
+        assert_eq!(2, super::ilog(999, 10));
+        assert_eq!(0, super::ilog(1, 7));
+        assert_eq!(None, checked_ilog(0, 10));
+        assert_eq!(None, checked_ilog(-5, 10));
+    }
+
+    #[test]
+    fn signum() {
+        assert_eq!(-1, Signum::signum(-42i32));
+        assert_eq!(0, Signum::signum(0i8));
+        assert_eq!(0, Signum::signum(0u32));
+        assert_eq!(1, Signum::signum(42usize));
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(u32::MAX - 2, Midpoint::midpoint(u32::MAX, u32::MAX - 4));
+        assert_eq!(5, Midpoint::midpoint(10u8, 0));
+        assert_eq!(0, Midpoint::midpoint(i8::MIN, i8::MAX));
+        assert_eq!(-2, Midpoint::midpoint(-5i32, 0));
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        type Mod7 = Mod<7>;
+
+        assert_eq!(Mod7::new(1), Mod7::new(5) + Mod7::new(3));
+        assert_eq!(Mod7::new(5), Mod7::new(1) - Mod7::new(3));
+        assert_eq!(Mod7::new(1), Mod7::new(4) * Mod7::new(2));
+        assert_eq!(Mod7::new(2), Mod7::new(2).pow(100));
+        assert_eq!(3, Mod7::from(24).value());
+        assert_eq!("6", Mod7::new(13).to_string());
+
+        let large = Mod::<{ u64::MAX }>::new(u64::MAX - 1);
+        assert_eq!(u64::MAX - 2, (large + large).value());
+        assert_eq!(1, (large * large).value());
+    }
+}
\ No newline at end of file
@@ -1,4 +1,11 @@
-use nom::{AsChar, Compare, Input, Parser, character::complete::{char, line_ending}, combinator::map, error::ParseError, multi::separated_list0, sequence::delimited};
+use std::{collections::HashMap, hash::Hash, ops::RangeInclusive};
+
+use nom::{AsChar, Compare, IResult, Input, Parser, branch::alt, bytes::complete::take_while1, character::complete::{anychar, char, line_ending, multispace0, none_of, not_line_ending, satisfy}, combinator::{eof, map, map_opt, map_res, not, value}, error::{FromExternalError, ParseError}, multi::{fold_many0, many0, many0_count, many1, separated_list0}, sequence::{delimited, pair, separated_pair, terminated}};
+use num_traits::{NumCast, PrimInt, Unsigned};
+
+pub use nom::character::complete::{space0, space1};
+
+use crate::{iterators::ExtraIter, parsing::{NomError, Parsable, ParsingResult}, spatial::{matrix::VariableRows, Matrix}};
 
 pub fn lines<I, O, E, F>(parser: F) -> impl Parser<I, Output = Vec<O>, Error = E> where
     F: Parser<I, Output = O, Error = E>,
@@ -11,6 +18,78 @@ pub fn lines<I, O, E, F>(parser: F) -> impl Parser<I, Output = Vec<O>, Error = E
     )
 }
 
+/// Applies `parser` to every line, skipping lines that are empty
+/// or consist solely of whitespace
+pub fn nonempty_lines<I, O, E, F>(parser: F) -> impl Parser<I, Output = Vec<O>, Error = E> where
+    F: Parser<I, Output = O, Error = E>,
+    E: ParseError<I>,
+    I: Clone + Input + Compare<&'static str>,
+    I::Item: AsChar
+{
+    delimited(
+        many0(blank_line),
+        separated_list0(
+            pair(line_ending, many0(blank_line)),
+            parser
+        ),
+        multispace0
+    )
+}
+
+/// Applies `parser` to every paragraph, where paragraphs are separated
+/// by one or more blank lines. Trailing blank lines are ignored
+pub fn paragraphs<I, O, E, F>(parser: F) -> impl Parser<I, Output = Vec<O>, Error = E> where
+    F: Parser<I, Output = O, Error = E>,
+    E: ParseError<I>,
+    I: Clone + Input + Compare<&'static str>,
+    I::Item: AsChar
+{
+    terminated(
+        separated_list0(
+            terminated(
+                pair(line_ending, many1(blank_line)),
+                not(pair(multispace0, eof))
+            ),
+            parser
+        ),
+        multispace0
+    )
+}
+
+/// Applies `parser` to every line, skipping comment lines
+/// that start with `prefix`
+pub fn skip_comments<I, O, E, F>(prefix: char, parser: F) -> impl Parser<I, Output = Vec<O>, Error = E> where
+    F: Parser<I, Output = O, Error = E>,
+    E: ParseError<I>,
+    I: Clone + Input + Compare<&'static str>,
+    I::Item: AsChar
+{
+    lines(alt((
+        map(pair(char(prefix), not_line_ending), |_| None),
+        map(parser, Some)
+    )))
+    .map(|lines| lines.into_iter().flatten().collect())
+}
+
+fn blank_line<I, E>(input: I) -> IResult<I, I, E> where
+    E: ParseError<I>,
+    I: Clone + Input + Compare<&'static str>,
+    I::Item: AsChar
+{
+    terminated(space0, line_ending).parse(input)
+}
+
+/// Applies `parser` while ignoring any surrounding whitespace,
+/// including line endings
+pub fn ws<I, O, E, F>(parser: F) -> impl Parser<I, Output = O, Error = E> where
+    F: Parser<I, Output = O, Error = E>,
+    E: ParseError<I>,
+    I: Input,
+    I::Item: AsChar
+{
+    delimited(multispace0, parser, multispace0)
+}
+
 pub fn parens<I, O, E, F>(parser: F) -> impl Parser<I, Output = O, Error = E> where
     F: Parser<I, Output = O, Error = E>,
     E: ParseError<I>,
@@ -102,4 +181,300 @@ pub trait Map3<I, O1, O2, O3, E> where
 impl<I, O1, O2, O3, E, F> Map3<I, O1, O2, O3, E> for F where
     F: Parser<I, Output = (O1, O2, O3), Error = E>,
     E: ParseError<I>
-{}
\ No newline at end of file
+{}
+
+/// Applies `parser` as many times as possible and returns
+/// the number of times it matched, without collecting the results
+pub fn many_count<I, O, E, F>(parser: F) -> impl Parser<I, Output = usize, Error = E> where
+    F: Parser<I, Output = O, Error = E>,
+    E: ParseError<I>,
+    I: Clone + Input
+{
+    many0_count(parser)
+}
+
+fn radix_number<I, O, E>(input: I, radix: u32) -> IResult<I, O, E> where
+    O: PrimInt + Unsigned,
+    E: ParseError<I>,
+    I: Input,
+    I::Item: AsChar
+{
+    map_opt(
+        take_while1(|c: I::Item| c.as_char().is_digit(radix)),
+        |digits: I| digits
+            .iter_elements()
+            .try_fold(O::zero(), |acc, c| {
+                let digit = <O as NumCast>::from(c.as_char().to_digit(radix)?)?;
+                acc.checked_mul(&<O as NumCast>::from(radix)?)?.checked_add(&digit)
+            })
+    )
+    .parse(input)
+}
+
+/// Parses an unsigned hexadecimal number, the digits are case-insensitive
+/// 
+/// Fails if the number does not fit in `O`
+pub fn hex_number<I, O, E>(input: I) -> IResult<I, O, E> where
+    O: PrimInt + Unsigned,
+    E: ParseError<I>,
+    I: Input,
+    I::Item: AsChar
+{
+    radix_number(input, 16)
+}
+
+/// Parses an unsigned binary number
+/// 
+/// Fails if the number does not fit in `O`
+pub fn binary_number<I, O, E>(input: I) -> IResult<I, O, E> where
+    O: PrimInt + Unsigned,
+    E: ParseError<I>,
+    I: Input,
+    I::Item: AsChar
+{
+    radix_number(input, 2)
+}
+
+/// Consumes exactly one character of any kind, including line endings
+pub fn any_char<I, E>(input: I) -> IResult<I, char, E> where
+    E: ParseError<I>,
+    I: Input,
+    I::Item: AsChar
+{
+    anychar(input)
+}
+
+/// Parses a single `1` or `0` as a boolean
+pub fn bit<I, E>(input: I) -> IResult<I, bool, E> where
+    E: ParseError<I>,
+    I: Input,
+    I::Item: AsChar
+{
+    alt((
+        value(true, char('1')),
+        value(false, char('0'))
+    ))
+    .parse(input)
+}
+
+/// Parses a grid of single decimal digits, like a heightmap,
+/// where every character is its own cell
+pub fn digit_grid<I, E>(input: I) -> IResult<I, Matrix<u8>, E> where
+    E: ParseError<I> + FromExternalError<I, VariableRows>,
+    I: Clone + Input + Compare<&'static str>,
+    I::Item: AsChar
+{
+    map_res(
+        lines(many1(map_opt(
+            satisfy(|c| c.is_ascii_digit()),
+            |c| c.to_digit(10)?.try_into().ok()
+        ))),
+        |rows| rows.into_iter().try_collecting()
+    )
+    .parse(input)
+}
+
+/// Extracts every number on a line, discarding any text between them
+/// 
+/// A `-` directly preceding a number is treated as a sign when `T` is signed.
+/// Digits are never skipped, so a number that does not fit in `T` fails the parser.
+/// Line endings are not consumed, so this can be combined with [`lines`]
+pub fn all_numbers<'a, T>(input: &'a str) -> ParsingResult<'a, Vec<T>> where
+    T: Parsable<'a>
+{
+    many0(alt((
+        map(T::parse, Some),
+        map(none_of("0123456789\r\n"), |_| None)
+    )))
+    .map(|numbers| numbers.into_iter().flatten().collect())
+    .parse(input)
+}
+
+/// Counts the non-overlapping matches of `parser` on a line,
+/// skipping over any characters that do not start a match
+/// 
+/// Line endings are not consumed, so this can be combined with [`lines`]
+pub fn count_occurrences<I, O, E, F>(parser: F) -> impl Parser<I, Output = usize, Error = E> where
+    F: Parser<I, Output = O, Error = E>,
+    E: ParseError<I>,
+    I: Clone + Input,
+    I::Item: AsChar
+{
+    fold_many0(
+        alt((
+            map(parser, |_| 1),
+            map(none_of("\r\n"), |_| 0)
+        )),
+        || 0,
+        |count, matched| count + matched
+    )
+}
+
+/// Parses lines of `key<separator>value` pairs into a [`HashMap`]
+/// 
+/// When a key occurs multiple times the last value wins
+pub fn key_value_map<'a, K, V>(separator: char) -> impl Parser<&'a str, Output = HashMap<K, V>, Error = NomError<'a>> where
+    K: Parsable<'a> + Hash + Eq,
+    V: Parsable<'a>
+{
+    lines(separated_pair(K::parse, char(separator), V::parse))
+        .map(HashMap::from_iter)
+}
+
+/// Parses a list of numbers separated by `separator`, like `1,2,3`
+pub fn separated_numbers<'a, T>(separator: char) -> impl Parser<&'a str, Output = Vec<T>, Error = NomError<'a>> where
+    T: Parsable<'a>
+{
+    separated_list0(char(separator), T::parse)
+}
+
+/// Parses an inclusive range written as `start-end`, like `2-4`
+pub fn range_parser<'a, T>(input: &'a str) -> ParsingResult<'a, RangeInclusive<T>> where
+    T: Parsable<'a>
+{
+    separated_pair(T::parse, char('-'), T::parse)
+        .map2(RangeInclusive::new)
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::bytes::complete::tag;
+
+    use crate::{parsing::ParserExt, spatial::Point};
+
+    use super::*;
+
+    #[test]
+    fn parse_hex_number() {
+        assert_eq!(31, hex_number::<_, u32, _>.run("1f").unwrap());
+        assert_eq!(255, hex_number::<_, u8, _>.run("FF").unwrap());
+        assert!(hex_number::<_, u8, _>.run("100").is_err());
+        assert!(hex_number::<_, u32, _>.run("1g").is_err());
+    }
+
+    #[test]
+    fn parse_binary_number() {
+        assert_eq!(10, binary_number::<_, u32, _>.run("1010").unwrap());
+        assert!(binary_number::<_, u32, _>.run("102").is_err());
+        assert!(binary_number::<_, u32, _>.run("").is_err());
+    }
+
+    #[test]
+    fn parse_bit() {
+        assert!(bit.run("1").unwrap());
+        assert!(!bit.run("0").unwrap());
+        assert!(bit.run("2").is_err());
+    }
+
+    #[test]
+    fn parse_digit_grid() {
+        let grid = digit_grid.run("219\n398").unwrap();
+        assert_eq!((3, 2), (grid.cols(), grid.rows()));
+        assert_eq!(8, grid[Point::new(2, 1)]);
+        assert_eq!(1, grid[Point::new(1, 0)]);
+
+        assert!(digit_grid.run("12\n3").is_err());
+        assert!(digit_grid.run("1a").is_err());
+    }
+
+    #[test]
+    fn parse_any_char() {
+        assert_eq!(Ok(("b", 'a')), any_char::<_, ()>("ab"));
+        assert_eq!(Ok(("b", '\n')), any_char::<_, ()>("\nb"));
+        assert!(any_char::<_, ()>("").is_err());
+    }
+
+    #[test]
+    fn parse_all_numbers() {
+        assert_eq!(vec![5, 3, 7], all_numbers::<u32>.run("move 5 from 3 to 7").unwrap());
+        assert_eq!(vec![-3, 4, 12], all_numbers::<i32>.run("x=-3, y=4 - 12").unwrap());
+        assert_eq!(vec![1, 3], all_numbers::<u32>.run("1-3").unwrap());
+        assert_eq!(Vec::<u32>::new(), all_numbers::<u32>.run("no numbers").unwrap());
+        assert_eq!(
+            vec![vec![1, 2], vec![3]],
+            lines(all_numbers::<u8>).run("a1b2\nc3").unwrap()
+        );
+
+        assert!(all_numbers::<u8>.run("x 300 y").is_err());
+        assert!(all_numbers::<i8>.run("x -200 y").is_err());
+        assert!(lines(all_numbers::<u8>).run("1\n256").is_err());
+    }
+
+    #[test]
+    fn parse_key_value_map() {
+        let map = key_value_map::<char, u32>('=').run("a=1\nb=2\na=3").unwrap();
+
+        assert_eq!(HashMap::from([('a', 3), ('b', 2)]), map);
+        assert!(key_value_map::<char, u32>(':').run("a=1").is_err());
+    }
+
+    #[test]
+    fn parse_nonempty_lines() {
+        assert_eq!(vec![1, 2, 3], nonempty_lines(u32::parse).run("\n1\n\n2\n  \n3\n\n").unwrap());
+        assert_eq!(vec![1, 2], nonempty_lines(u32::parse).run("1\r\n\r\n2").unwrap());
+        assert_eq!(Vec::<u32>::new(), nonempty_lines(u32::parse).run("\n\n").unwrap());
+    }
+
+    #[test]
+    fn parse_separated_numbers() {
+        assert_eq!(vec![1, 2, 3], separated_numbers::<u32>(',').run("1,2,3").unwrap());
+        assert_eq!(vec![-1, 2], separated_numbers::<i32>(' ').run("-1 2").unwrap());
+        assert!(separated_numbers::<u32>(',').run("1,,2").is_err());
+    }
+
+    #[test]
+    fn parse_range() {
+        assert_eq!(2..=4, range_parser::<u32>.run("2-4").unwrap());
+        assert_eq!(-3..=-1, range_parser::<i32>.run("-3--1").unwrap());
+        assert_eq!(
+            vec![1..=3, 5..=7],
+            separated_list0(char(','), range_parser::<u8>).run("1-3,5-7").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_paragraphs() {
+        let expected = vec![vec![1, 2], vec![3]];
+
+        assert_eq!(expected, paragraphs(lines(u32::parse)).run("1\n2\n\n3").unwrap());
+        assert_eq!(expected, paragraphs(lines(u32::parse)).run("1\n2\n\n\n3\n\n").unwrap());
+        assert_eq!(expected, paragraphs(lines(u32::parse)).run("1\r\n2\r\n\r\n3\r\n").unwrap());
+    }
+
+    #[test]
+    fn parse_many_count() {
+        assert_eq!(3, many_count(tag("ab")).run("ababab").unwrap());
+        assert_eq!(Ok(("a", 2)), many_count(tag::<_, _, ()>("ab")).parse("ababa"));
+        assert_eq!(Ok(("ba", 0)), many_count(tag::<_, _, ()>("ab")).parse("ba"));
+    }
+
+    #[test]
+    fn parse_count_occurrences() {
+        assert_eq!(2, count_occurrences(tag("the")).run("the cat sat on the mat").unwrap());
+        assert_eq!(1, count_occurrences(tag("aa")).run("aaa").unwrap());
+        assert_eq!(0, count_occurrences(tag("dog")).run("the cat").unwrap());
+        assert_eq!(
+            vec![1, 2],
+            lines(count_occurrences(tag("x"))).run("ax\nxx").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_skip_comments() {
+        assert_eq!(
+            vec![1, 2, 3],
+            skip_comments('#', u32::parse).run("# header\n1\n2\n# 4\n3\n#").unwrap()
+        );
+
+        assert_eq!(vec![-1], skip_comments(';', i32::parse).run(";;\n-1").unwrap());
+        assert!(skip_comments('#', u32::parse).run("1\n// 2").is_err());
+    }
+
+    #[test]
+    fn parse_ws() {
+        assert_eq!(42, ws(u32::parse).run("  42  ").unwrap());
+        assert_eq!(42, ws(u32::parse).run("42").unwrap());
+        assert_eq!(vec![1, 2], separated_list0(char(','), ws(u32::parse)).run(" 1 ,\n2").unwrap());
+    }
+}
\ No newline at end of file
@@ -1,16 +1,101 @@
-use nom::{IResult, Parser, character::complete::{i8, i16, i32, i64, i128, u8, u16, u32, u64, u128}, combinator::all_consuming};
+use nom::{IResult, Parser, branch::alt, bytes::complete::tag, character::complete::{i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, none_of}, combinator::{all_consuming, value}, error::{ErrorKind, ParseError}, number::complete::{double, float}};
+use nom_language::error::VerboseErrorKind;
 use thiserror::Error;
 
 use crate::tuples::snd;
 
 pub mod combinators;
 
+pub use yuki_derive::Parsable;
+
 pub type ParsingResult<'a, O> = IResult<&'a str, O, NomError<'a>>;
 pub type NomError<'a> = nom_language::error::VerboseError<&'a str>;
 
+/// An error for when the input could not be parsed
 #[derive(Debug, Error)]
-#[error("{0}")]
-pub struct ParsingError(String);
+#[error("{message}")]
+pub struct ParsingError {
+    message: String,
+    position: Option<Position>,
+    fragment: Option<String>,
+    expected: Vec<String>
+}
+
+impl ParsingError {
+    fn new(source: &str, error: &nom::Err<NomError<'_>>) -> Self {
+        let message = error.to_string();
+        let (nom::Err::Error(error) | nom::Err::Failure(error)) = error else {
+            return Self { message, position: None, fragment: None, expected: Vec::new() };
+        };
+
+        let remaining = error.errors
+            .first()
+            .map(|&(remaining, _)| remaining);
+
+        let position = remaining
+            .and_then(|remaining| source.substr_range(remaining))
+            .map(|range| Position::locate(source, range.start));
+
+        let fragment = remaining
+            .map(|remaining| remaining.lines().next().unwrap_or_default().to_owned());
+
+        let expected = error.errors
+            .iter()
+            .map(|(_, kind)| match kind {
+                VerboseErrorKind::Char(char) => format!("'{char}'"),
+                VerboseErrorKind::Context(context) => (*context).to_owned(),
+                VerboseErrorKind::Nom(kind) => kind.description().to_owned()
+            })
+            .collect();
+
+        Self { message, position, fragment, expected }
+    }
+
+    /// Returns the position in the input where parsing failed,
+    /// if it is known
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Returns the remainder of the line on which parsing failed,
+    /// if it is known
+    #[must_use]
+    pub fn fragment(&self) -> Option<&str> {
+        self.fragment.as_deref()
+    }
+
+    /// Returns what the parser expected to find,
+    /// from the innermost to the outermost parser
+    #[must_use]
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+}
+
+/// A location within the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// The byte offset from the start of the input
+    pub offset: usize,
+    /// The line number, starting at `1`
+    pub line: usize,
+    /// The column in characters, starting at `1`
+    pub column: usize
+}
+
+impl Position {
+    fn locate(source: &str, offset: usize) -> Self {
+        let preceding = &source[..offset];
+        let line_start = preceding.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            offset,
+            line: preceding.matches('\n').count() + 1,
+            column: preceding[line_start..].chars().count() + 1
+        }
+    }
+}
 
 pub trait Parsable<'a>: Sized {
     fn parse(input: &'a str) -> ParsingResult<'a, Self>;
@@ -27,7 +112,11 @@ pub fn parse_lines<'a, O>(input: &'a str) -> Result<Vec<O>, ParsingError> where
 {
     input
         .lines()
-        .map(parse::<O>)
+        .map(|line| all_consuming(O::parse)
+            .parse(line)
+            .map(snd)
+            .map_err(|err| ParsingError::new(input, &err))
+        )
         .collect()
 }
 
@@ -37,7 +126,7 @@ pub fn run_parser<'a, O, P>(parser: P, input: &'a str) -> Result<O, ParsingError
     all_consuming(parser)
         .parse(input)
         .map(snd)
-        .map_err(|err| ParsingError(err.to_string()))
+        .map_err(|err| ParsingError::new(input, &err))
 }
 
 pub trait ParserExt<'a, O> where
@@ -52,6 +141,14 @@ impl<'a, P, O> ParserExt<'a, O> for P where
     P: Parser<&'a str, Output = O, Error = NomError<'a>>,
 {}
 
+/// The error returned by a derived [`Parsable`] implementation
+/// when none of the tokens match
+#[doc(hidden)]
+#[must_use]
+pub fn token_error(input: &str) -> nom::Err<NomError<'_>> {
+    nom::Err::Error(NomError::from_error_kind(input, ErrorKind::Tag))
+}
+
 macro_rules! impl_parsable {
     ($type: ty, $parser: expr) => {
         impl<'a> Parsable<'a> for $type {
@@ -71,4 +168,93 @@ impl_parsable!(i32, i32);
 impl_parsable!(u64, u64);
 impl_parsable!(i64, i64);
 impl_parsable!(u128, u128);
-impl_parsable!(i128, i128);
\ No newline at end of file
+impl_parsable!(i128, i128);
+impl_parsable!(f32, float);
+impl_parsable!(f64, double);
+
+/// Parses any single character that is not a line ending,
+/// so grids of characters can be parsed using [`combinators::lines`].
+/// Use [`combinators::any_char`] to consume any character
+impl<'a> Parsable<'a> for char {
+    fn parse(input: &'a str) -> ParsingResult<'a, Self> {
+        none_of("\r\n").parse(input)
+    }
+}
+
+/// Parses the `true` and `false` keywords,
+/// see [`combinators::bit`] for parsing `1` and `0`
+impl<'a> Parsable<'a> for bool {
+    fn parse(input: &'a str) -> ParsingResult<'a, Self> {
+        alt((
+            value(true, tag("true")),
+            value(false, tag("false"))
+        ))
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spatial::Point;
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn parse_float() {
+        assert_eq!(3.5, parse::<f64>("3.5").unwrap());
+        assert_eq!(-2.0, parse::<f32>("-2.0").unwrap());
+        assert!(parse::<f64>("abc").is_err());
+    }
+
+    #[test]
+    fn parse_bool() {
+        assert!(parse::<bool>("true").unwrap());
+        assert!(!parse::<bool>("false").unwrap());
+        assert!(parse::<bool>("1").is_err());
+    }
+
+    #[test]
+    fn parse_char() {
+        assert_eq!(Ok(("bc", 'a')), char::parse("abc"));
+        assert!(char::parse("\nabc").is_err());
+        assert!(char::parse("").is_err());
+    }
+
+    #[test]
+    fn parsing_error_position() {
+        let error = parse_lines::<Point<u32>>("1,2\n3;4").unwrap_err();
+        assert_eq!(Some(Position { offset: 5, line: 2, column: 2 }), error.position());
+
+        let error = parse::<u32>("12a").unwrap_err();
+        assert_eq!(Some(Position { offset: 2, line: 1, column: 3 }), error.position());
+    }
+
+    #[test]
+    fn parsing_error_details() {
+        let error = parse_lines::<Point<u32>>("1,2\n3;4").unwrap_err();
+
+        assert_eq!(Some(";4"), error.fragment());
+        assert_eq!(["','"], error.expected());
+        assert!(!error.to_string().is_empty());
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Parsable)]
+    enum Tile {
+        #[token = "#"]
+        Wall,
+        #[token = "."]
+        Floor,
+        #[token = "<"]
+        #[token = "<="]
+        Slope
+    }
+
+    #[test]
+    fn derive_parsable() {
+        assert_eq!(Ok(("", Tile::Wall)), Tile::parse("#"));
+        assert_eq!(Ok(("#", Tile::Floor)), Tile::parse(".#"));
+        assert_eq!(Ok(("", Tile::Slope)), Tile::parse("<"));
+        assert_eq!(Ok(("", Tile::Slope)), Tile::parse("<="));
+        assert!(Tile::parse("x").is_err());
+    }
+}
\ No newline at end of file
@@ -1,7 +1,7 @@
 use std::{cmp::{max, min}, ops::{Add, Sub}};
 
 use itertools::{IntoChunks, Itertools};
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 use super::Point;
 
@@ -30,6 +30,18 @@ impl<T> Area<T> {
         }
     }
 
+    /// Creates the area spanned by two corners,
+    /// where `bottom_right` is included in the area
+    pub fn from_corners(top_left: Point<T>, bottom_right: Point<T>) -> Self where
+        T: Sub<Output=T> + TryInto<usize> + Copy
+    {
+        let dimensions = (bottom_right - top_left)
+            .cast::<usize>()
+            .unwrap() + Point::one();
+
+        Self::new(top_left, dimensions.into())
+    }
+
     /// Computes the surface area of the area
     pub fn surface_area(self) -> usize {
         let (width, height) = self.dimensions;
@@ -72,6 +84,64 @@ impl<T> Area<T> {
         Self::new(top_left, dimensions.into())
     }
 
+    /// Computes the center of the area.
+    /// For even dimensions the center is rounded towards the bottom-right
+    pub fn center(self) -> Point<T> where
+        T: TryFrom<usize> + Add<Output=T> + Copy
+    {
+        let (width, height) = self.dimensions;
+        self.position + Point::new(width / 2, height / 2).cast::<T>().unwrap()
+    }
+
+    /// Splits the area into four quadrants, ordered top-left, top-right,
+    /// bottom-left, bottom-right.
+    /// For odd dimensions the extra row or column goes to the bottom or right quadrants
+    pub fn quadrants(self) -> [Self; 4] where
+        T: TryFrom<usize> + Add<Output=T> + Copy
+    {
+        let (width, height) = self.dimensions;
+        let (left, top) = (width / 2, height / 2);
+        let (right, bottom) = (width - left, height - top);
+
+        let quadrant = |x: usize, y: usize, dimensions: (usize, usize)| Self::new(
+            self.position + Point::new(x, y).cast::<T>().unwrap(),
+            dimensions
+        );
+
+        [
+            quadrant(0, 0, (left, top)),
+            quadrant(left, 0, (right, top)),
+            quadrant(0, top, (left, bottom)),
+            quadrant(left, top, (right, bottom))
+        ]
+    }
+
+    /// Computes the smallest area containing both the area and `point`,
+    /// an empty area grows to cover just `point`
+    #[must_use]
+    pub fn include(self, point: Point<T>) -> Self where
+        T: Ord + One + Add<Output=T> + Sub<Output=T> + TryFrom<usize> + TryInto<usize> + Copy
+    {
+        if self.surface_area() == 0 { return Self::new(point, (1, 1)); }
+
+        let bottom_right = self.position + Point::from(self.dimensions).cast::<T>().unwrap();
+        let top_left = Point {
+            x: min(self.position.x, point.x),
+            y: min(self.position.y, point.y)
+        };
+
+        let bottom_right = Point {
+            x: max(bottom_right.x, point.x + T::one()),
+            y: max(bottom_right.y, point.y + T::one())
+        };
+
+        let dimensions = (bottom_right - top_left)
+            .cast::<usize>()
+            .unwrap();
+
+        Self::new(top_left, dimensions.into())
+    }
+
     /// Iterate over the points contained in the area.
     /// The points are visited left-to-right, top-to-bottom
     pub fn iter(&self) -> Iter<T> where
@@ -261,4 +331,47 @@ mod tests {
 
         assert_eq!(Area::<usize>::from_dimensions(0, 0), Area::bounding_area([]));
     }
+
+    #[test]
+    fn area_center() {
+        assert_eq!(Point::new(2, 2), Area::<usize>::from_dimensions(4, 4).center());
+        assert_eq!(Point::new(0, 2), Area::new(Point::new(-1, 1), (3, 2)).center());
+    }
+
+    #[test]
+    fn area_quadrants() {
+        assert_eq!(
+            [(0, 0), (2, 0), (0, 2), (2, 2)].map(|position| Area::new(Point::from(position), (2, 2))),
+            Area::<usize>::from_dimensions(4, 4).quadrants()
+        );
+
+        assert_eq!(
+            [
+                Area::new(Point::new(1, 1), (1, 1)),
+                Area::new(Point::new(2, 1), (2, 1)),
+                Area::new(Point::new(1, 2), (1, 2)),
+                Area::new(Point::new(2, 2), (2, 2))
+            ],
+            Area::new(Point::one(), (3, 3)).quadrants()
+        );
+    }
+
+    #[test]
+    fn area_include() {
+        let area = Area::new(Point::new(1, 1), (1, 1));
+
+        assert_eq!(Area::new(Point::new(1, 1), (3, 3)), area.include(Point::new(3, 3)));
+        assert_eq!(Area::new(Point::new(-1, -2), (3, 4)), area.include(Point::new(-1, -2)));
+        assert_eq!(area, area.include(Point::new(1, 1)));
+        assert_eq!(area, Area::from_dimensions(0, 0).include(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn area_from_corners() {
+        let area = Area::from_corners(Point::new(-1, 2), Point::new(1, 3));
+
+        assert_eq!(Area::new(Point::new(-1, 2), (3, 2)), area);
+        assert!(area.iter().contains(&Point::new(1, 3)));
+        assert_eq!(Some(Point::new(1, 3)), area.iter().next_back());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,197 @@
+use std::{cmp::{max, min}, ops::{Add, Sub}};
+
+use num_traits::Zero;
+
+use super::Point3;
+
+/// Represents a cuboid at a location in 3D space
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Cuboid<T = usize> {
+    pub position: Point3<T>,
+    pub dimensions: (usize, usize, usize)
+}
+
+impl<T> Cuboid<T> {
+    /// Creates a new cuboid at `position` with dimensions `dimensions`
+    #[must_use]
+    pub const fn new(position: Point3<T>, dimensions: (usize, usize, usize)) -> Self {
+        Self { position, dimensions }
+    }
+
+    /// Creates a new cuboid at the origin with the specified dimensions
+    #[must_use]
+    pub fn from_dimensions(width: usize, height: usize, depth: usize) -> Self where
+        T: Zero
+    {
+        Self {
+            position: Point3::zero(),
+            dimensions: (width, height, depth)
+        }
+    }
+
+    /// Computes the volume of the cuboid
+    pub fn volume(self) -> usize {
+        let (width, height, depth) = self.dimensions;
+        width * height * depth
+    }
+
+    /// Determines if `point` is contained in the cuboid
+    pub fn contains<U>(self, point: Point3<U>) -> bool where
+        T: Copy + PartialOrd + Add<Output=T> + TryFrom<usize>,
+        U: TryInto<T>
+    {
+        let far_corner = self.position + Point3::from(self.dimensions).cast::<T>().unwrap();
+        let Some(point) = point.cast::<T>() else { return false; };
+
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.z >= self.position.z
+            && point.x < far_corner.x
+            && point.y < far_corner.y
+            && point.z < far_corner.z
+    }
+
+    /// Computes the cuboid that is contained in both `self` and `other`
+    /// 
+    /// Returns [`None`] if the cuboids do not overlap
+    pub fn intersection(self, other: Self) -> Option<Self> where
+        T: Copy + Ord + Add<Output=T> + Sub<Output=T> + TryFrom<usize> + TryInto<usize>
+    {
+        let far_corner = |cuboid: Self| cuboid.position + Point3::from(cuboid.dimensions).cast::<T>().unwrap();
+        let (self_far, other_far) = (far_corner(self), far_corner(other));
+
+        let near = Point3 {
+            x: max(self.position.x, other.position.x),
+            y: max(self.position.y, other.position.y),
+            z: max(self.position.z, other.position.z)
+        };
+
+        let far = Point3 {
+            x: min(self_far.x, other_far.x),
+            y: min(self_far.y, other_far.y),
+            z: min(self_far.z, other_far.z)
+        };
+
+        if near.x >= far.x || near.y >= far.y || near.z >= far.z { return None; }
+
+        let dimensions = (far - near).cast::<usize>().unwrap();
+        Some(Self::new(near, dimensions.into()))
+    }
+
+    /// Iterate over the points contained in the cuboid.
+    /// The points are visited along `x` first, then `y`, then `z`
+    pub fn iter(&self) -> Iter<T> where
+        T: TryFrom<usize> + Add<Output=T> + Copy
+    {
+        self.into_iter()
+    }
+}
+
+impl<T> IntoIterator for Cuboid<T> where
+    T: TryFrom<usize> + Add<Output=T> + Copy
+{
+    type Item = Point3<T>;
+    type IntoIter = Iter<T>;
+
+    /// Iterate over the points contained in the cuboid.
+    /// The points are visited along `x` first, then `y`, then `z`
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            cuboid: self,
+            index: 0
+        }
+    }
+}
+
+impl<T> IntoIterator for &Cuboid<T> where
+    T: TryFrom<usize> + Add<Output=T> + Copy
+{
+    type Item = Point3<T>;
+    type IntoIter = Iter<T>;
+
+    /// Iterate over the points contained in the cuboid.
+    /// The points are visited along `x` first, then `y`, then `z`
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+/// Iterates over all the [`Point3`]s in a [`Cuboid`]
+/// 
+/// The iteration happens along `x` first, then `y`, then `z`
+pub struct Iter<T> {
+    cuboid: Cuboid<T>,
+    index: usize
+}
+
+impl<T> Iterator for Iter<T> where
+    T: TryFrom<usize> + Add<Output=T> + Copy
+{
+    type Item = Point3<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.cuboid.volume() { return None; }
+
+        let (width, height, _) = self.cuboid.dimensions;
+        let offset = Point3 {
+            x: self.index % width,
+            y: self.index / width % height,
+            z: self.index / (width * height)
+        }.cast::<T>().unwrap();
+
+        self.index += 1;
+        Some(self.cuboid.position + offset)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.cuboid.volume() - self.index;
+        (size, Some(size))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<T> where
+    T: TryFrom<usize> + Add<Output=T> + Copy
+{}
+
+#[cfg(test)]
+mod tests {
+    use itertools::assert_equal;
+
+    use super::*;
+
+    #[test]
+    fn cuboid_volume() {
+        assert_eq!(24, Cuboid::new(Point3::new(-1, 0, 3), (2, 3, 4)).volume());
+        assert_eq!(0, Cuboid::<usize>::from_dimensions(2, 0, 4).volume());
+    }
+
+    #[test]
+    fn cuboid_contains() {
+        let cuboid = Cuboid::new(Point3::new(-1, -1, -1), (3, 3, 3));
+
+        assert!(cuboid.contains(Point3::new(1, 0, -1)));
+        assert!(!cuboid.contains(Point3::new(2, 0, 0)));
+        assert!(!cuboid.contains(Point3::new(0, 0, -2)));
+    }
+
+    #[test]
+    fn cuboid_intersection() {
+        let a = Cuboid::new(Point3::new(0, 0, 0), (4, 4, 4));
+        let b = Cuboid::new(Point3::new(2, -1, 3), (4, 2, 4));
+
+        assert_eq!(Some(Cuboid::new(Point3::new(2, 0, 3), (2, 1, 1))), a.intersection(b));
+        assert_eq!(a.intersection(b), b.intersection(a));
+        assert_eq!(None, a.intersection(Cuboid::new(Point3::new(4, 0, 0), (1, 1, 1))));
+    }
+
+    #[test]
+    fn cuboid_into_iter() {
+        let cuboid = Cuboid::new(Point3::new(1, 1, 1), (2, 1, 2));
+
+        assert_eq!(4, cuboid.iter().len());
+        assert_equal(
+            [(1, 1, 1), (2, 1, 1), (1, 1, 2), (2, 1, 2)].map(Point3::from),
+            cuboid
+        );
+    }
+}
\ No newline at end of file
@@ -1,7 +1,9 @@
-use nom::{branch::alt, character::complete::{char, one_of}, combinator::value, Parser};
+use nom::{branch::alt, bytes::complete::tag, character::complete::one_of, combinator::value, Parser};
 use num_traits::Signed;
 
-use crate::parsing::{Parsable, ParsingResult};
+use crate::{num::CheckedAddSigned, parsing::{Parsable, ParsingResult}};
+
+use super::Point;
 
 /// Represents a set of directions
 pub trait Directions: Sized {
@@ -16,6 +18,31 @@ pub trait Directions: Sized {
     /// Creates an iterator over all directions in this set
     #[must_use]
     fn all() -> impl ExactSizeIterator<Item=Self>;
+
+    /// Creates an iterator over all directions in this set
+    /// paired with their direction vectors
+    #[must_use]
+    fn all_vectors<T: Signed>() -> impl ExactSizeIterator<Item=(Self, (T, T))> where
+        Self: Copy
+    {
+        Self::all().map(|direction| (direction, direction.vector()))
+    }
+}
+
+/// Represents a set of directions that can be turned into one another
+pub trait Turn: Sized {
+    /// The number of turns in the same direction that make up a full rotation
+    const FULL_ROTATION: usize;
+
+    /// Turn towards the next direction in the given rotational `direction`
+    #[must_use]
+    fn turn(self, direction: Rotation) -> Self;
+
+    /// Applies [`Turn::turn`] `times` times in the given rotational `direction`
+    #[must_use]
+    fn turn_n(self, direction: Rotation, times: usize) -> Self {
+        (0..times % Self::FULL_ROTATION).fold(self, |result, _| result.turn(direction))
+    }
 }
 
 /// Cardinal directions are the four prevalent directions in 2D space
@@ -56,7 +83,26 @@ impl Directions for Cardinal {
     }
 }
 
+impl Turn for Cardinal {
+    const FULL_ROTATION: usize = 4;
+
+    fn turn(self, direction: Rotation) -> Self {
+        // Resolves to the inherent `const` method
+        Self::turn(self, direction)
+    }
+}
+
 impl Cardinal {
+    /// Recovers the direction associated with a unit vector
+    /// 
+    /// Returns [`None`] if `vector` is not a cardinal unit vector
+    pub fn from_vector<T: Signed>((x, y): (T, T)) -> Option<Self> {
+        Self::all().find(|direction| {
+            let (dx, dy) = direction.vector::<T>();
+            dx == x && dy == y
+        })
+    }
+
     #[must_use]
     pub const fn turn(self, direction: Rotation) -> Self {
         use Rotation::{Clockwise as CW, CounterClockwise as CCW};
@@ -68,15 +114,63 @@ impl Cardinal {
             (CW, Self::West) | (CCW, Self::East) => Self::North
         }
     }
+
+    /// Turns around to face the opposite direction,
+    /// this is equivalent to [`Directions::inverted`]
+    #[must_use]
+    pub const fn turn_around(self) -> Self {
+        self.turn(Rotation::Clockwise).turn(Rotation::Clockwise)
+    }
+
+    /// Returns the compass bearing of the direction in degrees,
+    /// measured clockwise from north
+    #[must_use]
+    pub const fn degrees(self) -> u16 {
+        match self {
+            Self::North => 0,
+            Self::East => 90,
+            Self::South => 180,
+            Self::West => 270
+        }
+    }
+
+    /// Returns the position of the direction in [`Cardinal::all`]
+    #[must_use]
+    pub const fn index(self) -> usize {
+        match self {
+            Self::North => 0,
+            Self::East => 1,
+            Self::South => 2,
+            Self::West => 3
+        }
+    }
+
+    /// Recovers the direction at position `index` in [`Cardinal::all`]
+    /// 
+    /// Returns [`None`] if `index` is not in `0..4`
+    #[must_use]
+    pub const fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Self::North),
+            1 => Some(Self::East),
+            2 => Some(Self::South),
+            3 => Some(Self::West),
+            _ => None
+        }
+    }
 }
 
+/// Parses a cardinal direction from either an arrow (`^`, `>`, `v`, `<`),
+/// a relative letter (`U`, `R`, `D`, `L`) or a compass letter (`N`, `E`, `S`, `W`)
+/// 
+/// Letters are matched case-insensitively
 impl<'a> Parsable<'a> for Cardinal {
     fn parse(input: &'a str) -> ParsingResult<'a, Self> {
         alt((
-            value(Self::North, char('^')),
-            value(Self::East, char('>')),
-            value(Self::South, one_of("Vv")),
-            value(Self::West, char('<'))
+            value(Self::North, one_of("^UuNn")),
+            value(Self::East, one_of(">RrEe")),
+            value(Self::South, one_of("VvDdSs")),
+            value(Self::West, one_of("<LlWw"))
         ))
         .parse(input)
     }
@@ -88,6 +182,74 @@ impl<T: Signed> From<Cardinal> for (T, T) where {
     }
 }
 
+/// A set of [`Cardinal`] directions stored as a bitmask
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DirectionSet(u8);
+
+impl DirectionSet {
+    /// Creates an empty set
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    const fn mask(direction: Cardinal) -> u8 {
+        1 << direction.index()
+    }
+
+    /// Adds `direction` to the set
+    /// 
+    /// Returns whether the direction was newly inserted
+    pub const fn insert(&mut self, direction: Cardinal) -> bool {
+        let inserted = !self.contains(direction);
+        self.0 |= Self::mask(direction);
+        inserted
+    }
+
+    /// Removes `direction` from the set
+    /// 
+    /// Returns whether the direction was present in the set
+    pub const fn remove(&mut self, direction: Cardinal) -> bool {
+        let removed = self.contains(direction);
+        self.0 &= !Self::mask(direction);
+        removed
+    }
+
+    /// Determines if `direction` is in the set
+    #[must_use]
+    pub const fn contains(self, direction: Cardinal) -> bool {
+        self.0 & Self::mask(direction) != 0
+    }
+
+    /// Returns the number of directions in the set
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Determines if the set contains no directions
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterates over the directions in the set,
+    /// in the same order as [`Cardinal::all`]
+    pub fn iter(self) -> impl Iterator<Item=Cardinal> {
+        Cardinal::all().filter(move |&direction| self.contains(direction))
+    }
+}
+
+impl FromIterator<Cardinal> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item=Cardinal>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for direction in iter {
+            set.insert(direction);
+        }
+        set
+    }
+}
+
 /// The Ordinal directions sit between the [`Cardinal`] directions
 /// and represent diagonals in 2D space
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -127,6 +289,15 @@ impl Directions for Ordinal {
     }
 }
 
+impl Turn for Ordinal {
+    const FULL_ROTATION: usize = 4;
+
+    fn turn(self, direction: Rotation) -> Self {
+        // Resolves to the inherent `const` method
+        Self::turn(self, direction)
+    }
+}
+
 impl Ordinal {
     #[must_use]
     pub const fn turn(self, direction: Rotation) -> Self {
@@ -139,6 +310,29 @@ impl Ordinal {
             (CW, Self::NorthWest) | (CCW, Self::SouthEast) => Self::NorthEast
         }
     }
+
+    /// Returns the compass bearing of the direction in degrees,
+    /// measured clockwise from north
+    #[must_use]
+    pub const fn degrees(self) -> u16 {
+        match self {
+            Self::NorthEast => 45,
+            Self::SouthEast => 135,
+            Self::SouthWest => 225,
+            Self::NorthWest => 315
+        }
+    }
+
+    /// Decomposes the direction into its vertical and horizontal [`Cardinal`] components
+    #[must_use]
+    pub const fn components(self) -> (Cardinal, Cardinal) {
+        match self {
+            Self::NorthEast => (Cardinal::North, Cardinal::East),
+            Self::SouthEast => (Cardinal::South, Cardinal::East),
+            Self::SouthWest => (Cardinal::South, Cardinal::West),
+            Self::NorthWest => (Cardinal::North, Cardinal::West)
+        }
+    }
 }
 
 impl<T: Signed> From<Ordinal> for (T, T) where {
@@ -184,6 +378,15 @@ impl Directions for Compass {
     }
 }
 
+impl Turn for Compass {
+    const FULL_ROTATION: usize = 8;
+
+    fn turn(self, direction: Rotation) -> Self {
+        // Resolves to the inherent `const` method
+        Self::turn(self, direction)
+    }
+}
+
 impl Compass {
     #[must_use]
     pub const fn turn(self, direction: Rotation) -> Self {
@@ -201,6 +404,34 @@ impl Compass {
             (CW, Self::Ordinal(Ord::NorthWest)) | (CCW, Self::Ordinal(Ord::NorthEast)) => Self::Cardinal(Card::North)
         }
     }
+
+    /// Returns the compass bearing of the direction in degrees,
+    /// measured clockwise from north
+    #[must_use]
+    pub const fn degrees(self) -> u16 {
+        match self {
+            Self::Cardinal(direction) => direction.degrees(),
+            Self::Ordinal(direction) => direction.degrees()
+        }
+    }
+}
+
+/// Parses a compass direction from its abbreviation
+/// (`N`, `NE`, `E`, `SE`, `S`, `SW`, `W`, `NW`)
+impl<'a> Parsable<'a> for Compass {
+    fn parse(input: &'a str) -> ParsingResult<'a, Self> {
+        alt((
+            value(Self::Ordinal(Ordinal::NorthEast), tag("NE")),
+            value(Self::Ordinal(Ordinal::SouthEast), tag("SE")),
+            value(Self::Ordinal(Ordinal::SouthWest), tag("SW")),
+            value(Self::Ordinal(Ordinal::NorthWest), tag("NW")),
+            value(Self::Cardinal(Cardinal::North), tag("N")),
+            value(Self::Cardinal(Cardinal::East), tag("E")),
+            value(Self::Cardinal(Cardinal::South), tag("S")),
+            value(Self::Cardinal(Cardinal::West), tag("W"))
+        ))
+        .parse(input)
+    }
 }
 
 impl<T: Signed> From<Compass> for (T, T) where {
@@ -209,6 +440,71 @@ impl<T: Signed> From<Compass> for (T, T) where {
     }
 }
 
+/// Directions on a hexagonal grid with pointy-topped hexes
+/// 
+/// Hexes are addressed using axial coordinates, where `x` is the column (`q`)
+/// and `y` is the row (`r`). Moving east or west only changes `x`,
+/// while moving north-west or south-east only changes `y`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast
+}
+
+impl Directions for HexDirection {
+    fn vector<T: Signed>(self) -> (T, T) {
+        match self {
+            Self::East => (T::one(), T::zero()),
+            Self::NorthEast => (T::one(), -T::one()),
+            Self::NorthWest => (T::zero(), -T::one()),
+            Self::West => (-T::one(), T::zero()),
+            Self::SouthWest => (-T::one(), T::one()),
+            Self::SouthEast => (T::zero(), T::one())
+        }
+    }
+
+    fn inverted(self) -> Self {
+        match self {
+            Self::East => Self::West,
+            Self::NorthEast => Self::SouthWest,
+            Self::NorthWest => Self::SouthEast,
+            Self::West => Self::East,
+            Self::SouthWest => Self::NorthEast,
+            Self::SouthEast => Self::NorthWest
+        }
+    }
+
+    fn all() -> impl ExactSizeIterator<Item=Self> {
+        [
+            Self::East,
+            Self::NorthEast,
+            Self::NorthWest,
+            Self::West,
+            Self::SouthWest,
+            Self::SouthEast
+        ].into_iter()
+    }
+}
+
+impl<T: Signed> From<HexDirection> for (T, T) where {
+    fn from(val: HexDirection) -> Self {
+        val.vector()
+    }
+}
+
+/// Determines the [`Compass`] direction to move in to get from `from` to `to`
+/// 
+/// Returns [`None`] if the points are not orthogonally or diagonally adjacent
+pub fn direction_between<T>(from: Point<T>, to: Point<T>) -> Option<Compass> where
+    T: CheckedAddSigned + PartialEq + Copy
+{
+    Compass::all().find(|direction| from.add_signed(direction.vector()) == Some(to))
+}
+
 /// A rotation direction represents a relative direction, not unlike `left` or `right`.
 /// These can be used to create new directions from existing ones
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
@@ -225,4 +521,174 @@ impl Rotation {
             Self::CounterClockwise => Self::Clockwise
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::assert_equal;
+    use nom::multi::many1;
+
+    use crate::parsing::{parse, ParserExt};
+    use super::*;
+
+    #[test]
+    fn cardinal_from_vector() {
+        assert_eq!(Some(Cardinal::North), Cardinal::from_vector((0, -1)));
+        assert_eq!(Some(Cardinal::East), Cardinal::from_vector((1, 0)));
+        assert_eq!(Some(Cardinal::South), Cardinal::from_vector((0, 1)));
+        assert_eq!(Some(Cardinal::West), Cardinal::from_vector((-1, 0)));
+        assert_eq!(None, Cardinal::from_vector((1, 1)));
+        assert_eq!(None, Cardinal::from_vector((0, 2)));
+    }
+
+    #[test]
+    fn cardinal_parse() {
+        use Cardinal::{North, East, South, West};
+
+        let expected = [North, East, South, West];
+        for input in ["^>v<", "^>V<", "URDL", "urdl", "NESW", "nesw"] {
+            assert_eq!(expected.to_vec(), many1(Cardinal::parse).run(input).unwrap());
+        }
+
+        assert_eq!(North, parse::<Cardinal>("u").unwrap());
+        assert!(parse::<Cardinal>("x").is_err());
+    }
+
+    #[test]
+    fn turn_n() {
+        use Rotation::{Clockwise as CW, CounterClockwise as CCW};
+
+        for direction in Cardinal::all() {
+            assert_eq!(direction, direction.turn_n(CW, 4));
+            assert_eq!(direction.turn_around(), direction.turn_n(CCW, 2));
+        }
+
+        for direction in Ordinal::all() {
+            assert_eq!(direction, direction.turn_n(CCW, 4));
+            assert_eq!(direction.inverted(), direction.turn_n(CW, 2));
+        }
+
+        for direction in Compass::all() {
+            assert_eq!(direction, direction.turn_n(CW, 8));
+            assert_eq!(direction.inverted(), direction.turn_n(CCW, 4));
+        }
+
+        assert_eq!(Cardinal::West, Cardinal::North.turn_n(CW, 7));
+    }
+
+    #[test]
+    fn hex_direction_neighbours() {
+        assert_equal(
+            [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)].map(Point::from),
+            Point::<i32>::zero().neighbours::<HexDirection>()
+        );
+
+        for direction in HexDirection::all() {
+            let (x, y) = direction.vector::<i32>();
+            assert_eq!((-x, -y), direction.inverted().vector());
+        }
+    }
+
+    #[test]
+    fn degrees() {
+        assert_eq!(0, Cardinal::North.degrees());
+        assert_eq!(90, Cardinal::East.degrees());
+        assert_eq!(180, Cardinal::South.degrees());
+        assert_eq!(270, Cardinal::West.degrees());
+        assert_eq!(45, Ordinal::NorthEast.degrees());
+        assert_eq!(315, Compass::Ordinal(Ordinal::NorthWest).degrees());
+    }
+
+    #[test]
+    fn direction_between() {
+        use super::direction_between;
+
+        assert_eq!(
+            Some(Compass::Cardinal(Cardinal::West)),
+            direction_between(Point::<u32>::new(1, 1), Point::new(0, 1))
+        );
+
+        assert_eq!(
+            Some(Compass::Ordinal(Ordinal::SouthEast)),
+            direction_between(Point::<i32>::new(-1, 0), Point::new(0, 1))
+        );
+
+        assert_eq!(None, direction_between(Point::<i32>::zero(), Point::new(2, 0)));
+        assert_eq!(None, direction_between(Point::<i32>::zero(), Point::zero()));
+    }
+
+    #[test]
+    fn cardinal_all_vectors() {
+        assert_equal(
+            [
+                (Cardinal::North, (0, -1)),
+                (Cardinal::East, (1, 0)),
+                (Cardinal::South, (0, 1)),
+                (Cardinal::West, (-1, 0))
+            ],
+            Cardinal::all_vectors::<i32>()
+        );
+    }
+
+    #[test]
+    fn compass_parse() {
+        let tokens = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+
+        for (token, direction) in tokens.into_iter().zip(Compass::all()) {
+            assert_eq!(direction, parse::<Compass>(token).unwrap());
+        }
+
+        assert!(parse::<Compass>("X").is_err());
+        assert!(parse::<Compass>("NN").is_err());
+    }
+
+    #[test]
+    fn ordinal_components() {
+        assert_eq!((Cardinal::North, Cardinal::East), Ordinal::NorthEast.components());
+        assert_eq!((Cardinal::South, Cardinal::East), Ordinal::SouthEast.components());
+        assert_eq!((Cardinal::South, Cardinal::West), Ordinal::SouthWest.components());
+        assert_eq!((Cardinal::North, Cardinal::West), Ordinal::NorthWest.components());
+
+        for direction in Ordinal::all() {
+            let (vertical, horizontal) = direction.components();
+            let (x, y) = horizontal.vector::<i32>();
+            assert_eq!(direction.vector(), (x, y + vertical.vector::<i32>().1));
+        }
+    }
+
+    #[test]
+    fn direction_set_operations() {
+        let mut set = DirectionSet::new();
+        assert!(set.is_empty());
+
+        assert!(set.insert(Cardinal::West));
+        assert!(set.insert(Cardinal::North));
+        assert!(!set.insert(Cardinal::West));
+        assert!(set.contains(Cardinal::North));
+        assert!(!set.contains(Cardinal::East));
+        assert_eq!(2, set.len());
+
+        assert!(set.remove(Cardinal::North));
+        assert!(!set.remove(Cardinal::North));
+        assert!(!set.contains(Cardinal::North));
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    fn direction_set_iter() {
+        let set: DirectionSet = [Cardinal::West, Cardinal::South, Cardinal::North].into_iter().collect();
+
+        assert_equal([Cardinal::North, Cardinal::South, Cardinal::West], set.iter());
+        assert_eq!(0, DirectionSet::new().iter().count());
+    }
+
+    #[test]
+    fn cardinal_index() {
+        for (index, direction) in Cardinal::all().enumerate() {
+            assert_eq!(index, direction.index());
+            assert_eq!(Some(direction), Cardinal::from_index(index));
+        }
+
+        assert_eq!(None, Cardinal::from_index(4));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+use super::Point;
+
+/// Computes the cross product of the vectors `origin -> a` and `origin -> b`
+/// 
+/// The result is positive if `origin`, `a` and `b` make a counterclockwise turn,
+/// negative for a clockwise turn and zero if the points are collinear
+#[must_use]
+pub const fn cross(origin: Point<i64>, a: Point<i64>, b: Point<i64>) -> i64 {
+    (a.x - origin.x) * (b.y - origin.y) - (a.y - origin.y) * (b.x - origin.x)
+}
+
+/// Computes the convex hull of a set of points using Andrew's monotone chain algorithm
+/// 
+/// The vertices of the hull are returned in counterclockwise order,
+/// starting from the point with the lowest `x` (and then `y`) coordinate.
+/// Points on the edges of the hull are not included
+#[must_use]
+pub fn convex_hull(points: &[Point<i64>]) -> Vec<Point<i64>> {
+    let mut points = points.to_vec();
+    points.sort_unstable_by_key(|point| (point.x, point.y));
+    points.dedup();
+
+    if points.len() < 3 { return points; }
+
+    let mut hull = half_hull(points.iter().copied());
+    hull.extend(half_hull(points.into_iter().rev()));
+    hull
+}
+
+/// Builds the lower half of the hull for points sorted in ascending order,
+/// or the upper half for points sorted in descending order.
+/// The last point is omitted as it starts the other half
+fn half_hull(points: impl Iterator<Item=Point<i64>>) -> Vec<Point<i64>> {
+    let mut hull: Vec<Point<i64>> = Vec::new();
+
+    for point in points {
+        while let [.., a, b] = hull[..] && cross(a, b, point) <= 0 {
+            hull.pop();
+        }
+
+        hull.push(point);
+    }
+
+    hull.pop();
+    hull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geometry_cross() {
+        assert_eq!(1, cross(Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)));
+        assert_eq!(-1, cross(Point::new(0, 0), Point::new(0, 1), Point::new(1, 0)));
+        assert_eq!(0, cross(Point::new(0, 0), Point::new(1, 1), Point::new(3, 3)));
+    }
+
+    #[test]
+    fn geometry_convex_hull() {
+        let points = [(0, 0), (2, 0), (1, 1), (2, 2), (0, 2), (1, 0)].map(Point::from);
+        assert_eq!(
+            [(0, 0), (2, 0), (2, 2), (0, 2)].map(Point::from).to_vec(),
+            convex_hull(&points)
+        );
+
+        let collinear = [(2, 2), (0, 0), (1, 1), (3, 3)].map(Point::from);
+        assert_eq!(vec![Point::new(0, 0), Point::new(3, 3)], convex_hull(&collinear));
+
+        assert_eq!(vec![Point::new(1, 1)], convex_hull(&[Point::new(1, 1), Point::new(1, 1)]));
+        assert_eq!(Vec::<Point<i64>>::new(), convex_hull(&[]));
+    }
+}
\ No newline at end of file
@@ -1,12 +1,12 @@
-use std::{mem, ops::{Index, IndexMut}};
+use std::{collections::{HashSet, VecDeque}, mem, ops::{Index, IndexMut}};
 
-use nom::{multi::many1, Parser, combinator::map_res};
+use nom::{multi::many1, Parser, character::complete::none_of, combinator::{map_opt, map_res}};
 use thiserror::Error;
 use itertools::Itertools;
 
-use crate::{iterators::{Enumerate2D, ExtraIter, TryFromIterator}, parsing::{combinators::lines, Parsable, ParsingResult}};
+use crate::{iterators::{Enumerate2D, ExtraIter, TryFromIterator}, parsing::{combinators::lines, NomError, Parsable, ParsingResult}};
 
-use super::Point;
+use super::{direction::{Cardinal, Compass, Directions}, Area, Point};
 
 /// A Matrix is a dense `N * M` 2D array
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -102,6 +102,17 @@ impl<'a, T> IntoIterator for &'a mut Matrix<T> {
 }
 
 impl<T> Matrix<T> {
+    /// Constructs a matrix from an iterator of rows
+    /// 
+    /// This is equivalent to `iter.into_iter().try_collecting()`
+    pub fn from_row_iter<I>(iter: I) -> Result<Self, VariableRows> where
+        I: IntoIterator,
+        I::Item: IntoIterator<Item=T>,
+        <I::Item as IntoIterator>::IntoIter: Clone
+    {
+        iter.into_iter().try_collecting()
+    }
+
     /// Returns the amount of columns the matrix has
     #[must_use]
     pub const fn cols(&self) -> usize {
@@ -149,12 +160,13 @@ impl<T> Matrix<T> {
 
     /// Create an iterator over all the rows in the matrix
     pub fn iter_rows(&self) -> core::slice::ChunksExact<'_, T> {
-        self.data.chunks_exact(self.columns)
+        // A matrix without columns has no data, but chunks must be non-empty
+        self.data.chunks_exact(self.columns.max(1))
     }
 
     /// Create a mutable iterator over all the rows in the matrix
     pub fn iter_rows_mut(&mut self) -> core::slice::ChunksExactMut<'_, T> {
-        self.data.chunks_exact_mut(self.columns)
+        self.data.chunks_exact_mut(self.columns.max(1))
     }
 
     /// Creates an iterator over all the columns in the matrix
@@ -198,6 +210,73 @@ impl<T> Matrix<T> {
         Self { data, columns }
     }
 
+    /// Creates an iterator over the positions and values of the cells
+    /// adjacent to `point` in all `D` directions that lie inside the matrix
+    pub fn neighbours<D: Directions>(&self, point: Point<usize>) -> impl Iterator<Item=(Point<usize>, &T)> {
+        point
+            .neighbours::<D>()
+            .filter_map(|neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// Creates an iterator over the positions and values of the cells
+    /// orthogonally adjacent to `point`
+    pub fn neighbours4(&self, point: Point<usize>) -> impl Iterator<Item=(Point<usize>, &T)> {
+        self.neighbours::<Cardinal>(point)
+    }
+
+    /// Creates an iterator over the positions and values of the cells
+    /// orthogonally or diagonally adjacent to `point`
+    pub fn neighbours8(&self, point: Point<usize>) -> impl Iterator<Item=(Point<usize>, &T)> {
+        self.neighbours::<Compass>(point)
+    }
+
+    /// Finds all the cells reachable from `start` by repeatedly moving
+    /// in a cardinal direction between cells for which `connected` holds
+    /// 
+    /// `connected` is called with the current cell and its neighbour.
+    /// Returns an empty set if `start` is out of bounds
+    pub fn flood_fill<F>(&self, start: Point<usize>, connected: F) -> HashSet<Point<usize>> where
+        F: Fn(&T, &T) -> bool
+    {
+        let mut region = HashSet::new();
+        if self.get(start).is_none() { return region; }
+
+        let mut queue = VecDeque::from([start]);
+        region.insert(start);
+
+        while let Some(point) = queue.pop_front() {
+            let cell = &self[point];
+
+            for neighbour in point.neighbours::<Cardinal>() {
+                let Some(other) = self.get(neighbour) else { continue };
+                if connected(cell, other) && region.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Partitions all the cells of the matrix into regions,
+    /// where cells are joined according to [`Self::flood_fill`]
+    pub fn connected_components<F>(&self, connected: F) -> Vec<HashSet<Point<usize>>> where
+        F: Fn(&T, &T) -> bool
+    {
+        let mut visited = HashSet::new();
+        let mut components = Vec::new();
+
+        for point in Area::<usize>::from_dimensions(self.cols(), self.rows()) {
+            if visited.contains(&point) { continue; }
+
+            let component = self.flood_fill(point, &connected);
+            visited.extend(component.iter().copied());
+            components.push(component);
+        }
+
+        components
+    }
+
     /// Perform a mapping on every element of the matrix
     /// using the specified mapping function
     #[must_use]
@@ -231,6 +310,21 @@ impl<'a, T> Parsable<'a> for Matrix<T> where
     }
 }
 
+/// Parses a grid where every character is its own cell,
+/// `mapper` converts a character to a cell and fails the parser by returning [`None`]
+pub fn char_grid<'a, T, F>(mapper: F) -> impl Parser<&'a str, Output = Matrix<T>, Error = NomError<'a>> where
+    T: Clone,
+    F: Fn(char) -> Option<T>
+{
+    move |input| map_res(
+        lines(
+            many1(map_opt(none_of("\r\n"), &mapper))
+        ),
+        |matrix| matrix.into_iter().try_collecting()
+    )
+    .parse(input)
+}
+
 /// An iterator that drains a matrix row by row
 pub struct IntoRows<T> {
     data: Vec<T>,
@@ -248,4 +342,110 @@ impl<T> Iterator for IntoRows<T> {
 
         Some(chunk)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::assert_equal;
+
+    use crate::parsing::ParserExt;
+    use super::*;
+
+    #[test]
+    fn matrix_from_row_iter() {
+        let matrix = Matrix::from_row_iter((0..3).map(|y| (0..3).map(move |x| x + y))).unwrap();
+
+        assert_eq!((3, 3), (matrix.cols(), matrix.rows()));
+        assert_eq!(3, matrix[Point::new(1, 2)]);
+        assert_equal([0, 1, 2, 1, 2, 3, 2, 3, 4].iter(), &matrix);
+
+        assert!(Matrix::from_row_iter([vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn matrix_flood_fill() {
+        let matrix = Matrix::from_row_iter(["aab", "abb", "aba"].map(str::chars)).unwrap();
+
+        assert_eq!(
+            HashSet::from([(0, 0), (1, 0), (0, 1), (0, 2)].map(Point::from)),
+            matrix.flood_fill(Point::zero(), PartialEq::eq)
+        );
+
+        assert_eq!(
+            HashSet::from([Point::new(2, 2)]),
+            matrix.flood_fill(Point::new(2, 2), PartialEq::eq)
+        );
+
+        assert!(matrix.flood_fill(Point::new(3, 0), PartialEq::eq).is_empty());
+    }
+
+    #[test]
+    fn matrix_connected_components() {
+        let matrix = Matrix::from_row_iter(["aab", "bba", "aaa"].map(str::chars)).unwrap();
+        let components = matrix.connected_components(PartialEq::eq);
+
+        assert_eq!(4, components.len());
+        assert_eq!(HashSet::from([Point::new(0, 0), Point::new(1, 0)]), components[0]);
+        assert_eq!(HashSet::from([Point::new(2, 0)]), components[1]);
+        assert_eq!(9, components.iter().map(HashSet::len).sum::<usize>());
+
+        assert!(Matrix::<char>::from_row_iter(Vec::<Vec<char>>::new()).unwrap().connected_components(PartialEq::eq).is_empty());
+    }
+
+    #[test]
+    fn matrix_neighbours() {
+        let matrix = Matrix::from_row_iter((0..3).map(|y| (0..3).map(move |x| x + 3 * y))).unwrap();
+
+        assert_equal(
+            [(Point::new(1, 0), &1), (Point::new(0, 1), &3)],
+            matrix.neighbours4(Point::zero())
+        );
+
+        assert_eq!(3, matrix.neighbours8(Point::zero()).count());
+        assert_eq!(4, matrix.neighbours4(Point::one()).count());
+        assert_eq!(8, matrix.neighbours8(Point::one()).count());
+        assert_eq!(32, matrix.neighbours8(Point::one()).map(|(_, &value)| value).sum::<i32>());
+    }
+
+    #[test]
+    fn matrix_transpose_empty() {
+        let empty = Matrix::<u8>::from_row_iter(Vec::<Vec<u8>>::new()).unwrap();
+        let transposed = empty.clone().transpose();
+        assert_eq!((0, 0), (transposed.cols(), transposed.rows()));
+        assert_eq!(empty, transposed);
+
+        let no_columns = Matrix::<u8>::from_row_iter([[], [], []]).unwrap();
+        let transposed = no_columns.transpose();
+        assert_eq!((0, 0), (transposed.cols(), transposed.rows()));
+        assert_eq!(0, transposed.iter_rows().count());
+        assert_eq!(0, transposed.map(|(_, &value)| value).iter().count());
+        assert_eq!(0, transposed.into_cols().count());
+    }
+
+    #[test]
+    fn matrix_index_mut_matches_index() {
+        let mut matrix = Matrix::from_row_iter([[0; 3]; 2]).unwrap();
+
+        matrix[Point::new(2, 1)] = 5;
+        matrix[Point::new(1, 0)] = 3;
+
+        assert_eq!(5, matrix[Point::new(2, 1)]);
+        assert_eq!(3, matrix[Point::new(1, 0)]);
+        assert_equal([&0, &3, &0, &0, &0, &5], &matrix);
+    }
+
+    #[test]
+    fn matrix_char_grid() {
+        let wall = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None
+        };
+
+        let matrix = char_grid(wall).run("#.#\n.#.").unwrap();
+        assert_eq!(Matrix::from_row_iter([[true, false, true], [false, true, false]]).unwrap(), matrix);
+
+        assert!(char_grid(wall).run("#x#").is_err());
+        assert!(char_grid(wall).run("#.\n#").is_err());
+    }
 }
\ No newline at end of file
@@ -1,8 +1,13 @@
 pub mod matrix;
 pub mod direction;
 pub mod point;
+pub mod point3;
 pub mod area;
+pub mod cuboid;
+pub mod geometry;
 
 pub type Point<T> = point::Point<T>;
+pub type Point3<T> = point3::Point3<T>;
 pub type Matrix<T> = matrix::Matrix<T>;
-pub type Area<T> = area::Area<T>;
\ No newline at end of file
+pub type Area<T> = area::Area<T>;
+pub type Cuboid<T> = cuboid::Cuboid<T>;
\ No newline at end of file
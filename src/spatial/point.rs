@@ -1,12 +1,13 @@
 use std::cmp::{minmax, Ordering};
-use std::ops::{Add, Sub};
+use std::iter;
+use std::ops::{Add, Mul, Sub};
 
 use nom::Parser;
 use nom::character::complete::char;
 use nom::sequence::separated_pair;
-use num_traits::{Num, One, Zero};
+use num_traits::{Num, One, PrimInt, Signed, Zero};
 
-use crate::num::AbsDiff;
+use crate::num::{AbsDiff, Signum};
 use crate::parsing::{Parsable, ParsingResult};
 
 use super::super::num::CheckedAddSigned;
@@ -52,6 +53,16 @@ impl<T> Point<T> {
         D::all().filter_map(move |direction| self.add_signed(direction.vector()))
     }
 
+    /// Moves a single step in `direction`
+    /// 
+    /// Returns [`None`] if the resulting point is not representable by `T`
+    pub fn step<D>(self, direction: D) -> Option<Self> where
+        T: CheckedAddSigned,
+        D: Directions
+    {
+        self.add_signed(direction.vector())
+    }
+
     /// Attempts to add a signed [`Point<U>`] to `self`,
     /// 
     /// returns [`None`] if the result is not a valid `T`
@@ -84,6 +95,22 @@ impl<T> Point<T> {
         Self { x: T::one(), y: T::one() }
     }
 
+    /// Computes the sign of each of the components of the point,
+    /// for a direction vector this yields the unit step towards it
+    #[must_use]
+    pub fn signum(self) -> Self where
+        T: Signum
+    {
+        Self { x: self.x.signum(), y: self.y.signum() }
+    }
+
+    /// Computes the sum of the components of the point
+    pub fn sum(self) -> T where
+        T: Add<Output=T>
+    {
+        self.x + self.y
+    }
+
     #[must_use]
     /// Computes the manhatten distance of `self` to `other`
     /// 
@@ -98,6 +125,101 @@ impl<T> Point<T> {
         max_x - min_x + max_y - min_y
     }
 
+    /// Creates an iterator over every grid cell on the line from `self` to `other`
+    /// using Bresenham's line algorithm, including both endpoints
+    pub fn line_to(self, other: Self) -> impl Iterator<Item=Self> where
+        T: PrimInt + Signed
+    {
+        let dx = (other.x - self.x).abs();
+        let dy = -(other.y - self.y).abs();
+        let step = Self::new((other.x - self.x).signum(), (other.y - self.y).signum());
+
+        let mut error = dx + dy;
+        let mut current = Some(self);
+
+        iter::from_fn(move || {
+            let point = current?;
+            if point == other {
+                current = None;
+                return Some(point);
+            }
+
+            let mut next = point;
+            let doubled = error + error;
+            if doubled >= dy {
+                error = error + dy;
+                next.x = next.x + step.x;
+            }
+
+            if doubled <= dx {
+                error = error + dx;
+                next.y = next.y + step.y;
+            }
+
+            current = Some(next);
+            Some(point)
+        })
+    }
+
+    /// Creates an iterator over the points on the segment from `self` to `other`, including both endpoints
+    /// 
+    /// Returns [`None`] if the segment is not horizontal, vertical or exactly diagonal,
+    /// see [`Self::line_to`] for arbitrary lines
+    pub fn straight_line_to(self, other: Self) -> Option<impl Iterator<Item=Self>> where
+        T: PrimInt + Signed
+    {
+        let delta = other - self;
+        let is_straight = delta.x.is_zero() || delta.y.is_zero() || delta.x.abs() == delta.y.abs();
+        if !is_straight { return None; }
+
+        let step = Self::new(delta.x.signum(), delta.y.signum());
+        Some(iter::successors(Some(self), move |&point| (point != other).then(|| point + step)))
+    }
+
+    /// Creates an iterator over the points at exactly manhattan distance `radius` from `self`,
+    /// forming a diamond of `4 * radius` points. A radius of `0` yields just `self`
+    pub fn manhattan_ring(self, radius: T) -> impl Iterator<Item=Self> where
+        T: PrimInt + Signed
+    {
+        let start = (!radius.is_negative()).then_some(-radius);
+
+        iter::successors(start, move |&dx| (dx < radius).then(|| dx + T::one()))
+            .flat_map(move |dx| {
+                let dy = radius - dx.abs();
+                [Some(dy), (!dy.is_zero()).then(|| -dy)]
+                    .into_iter()
+                    .flatten()
+                    .map(move |dy| self + Self::new(dx, dy))
+            })
+    }
+
+    #[must_use]
+    /// Computes the squared euclidean distance of `self` to `other`
+    /// 
+    /// This avoids taking a square root, while preserving the ordering of distances
+    pub fn distance_squared(self, other: Self) -> T where
+        T: Ord + Copy + Sub<Output=T> + Add<Output=T> + Mul<Output=T>
+    {
+        let [min_x, max_x] = minmax(self.x, other.x);
+        let [min_y, max_y] = minmax(self.y, other.y);
+        let (dx, dy) = (max_x - min_x, max_y - min_y);
+
+        dx * dx + dy * dy
+    }
+
+    /// Finds the candidate nearest to `self` by euclidean distance,
+    /// ties are broken in favour of the earliest candidate
+    /// 
+    /// Returns [`None`] if there are no candidates
+    pub fn closest_to<I>(self, candidates: I) -> Option<Self> where
+        T: Ord + Copy + Sub<Output=T> + Add<Output=T> + Mul<Output=T>,
+        I: IntoIterator<Item=Self>
+    {
+        candidates
+            .into_iter()
+            .min_by_key(|&candidate| self.distance_squared(candidate))
+    }
+
     #[must_use]
     /// Computes the absolute difference between two points
     pub fn abs_diff(self, rhs: Self) -> Point<T::Unsigned> where 
@@ -144,7 +266,7 @@ impl<'a, T> Parsable<'a> for Point<T> where
 {
     fn parse(input: &'a str) -> ParsingResult<'a, Self> {
         separated_pair(T::parse, char(','), T::parse)
-            .map(Point::from)
+            .map(Self::from)
             .parse(input)
     }
 }
@@ -173,6 +295,8 @@ impl_scalar_op!(Div, div, /);
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use itertools::assert_equal;
     use crate::spatial::direction;
     use super::*;
@@ -221,6 +345,88 @@ mod tests {
         assert_eq!(
             Point::<u16>::new(2, 8),
             Point::<i16>::new(-2, 3).abs_diff(Point::<i16>::new(-4, -5))
-        )
+        );
+    }
+
+    #[test]
+    fn point_line_to() {
+        assert_equal(
+            [(2, 1), (1, 1), (0, 1), (-1, 1)].map(Point::from),
+            Point::new(2, 1).line_to(Point::new(-1, 1))
+        );
+
+        assert_equal(
+            [(0, 0), (-1, -1), (-2, -2), (-3, -3)].map(Point::from),
+            Point::new(0, 0).line_to(Point::new(-3, -3))
+        );
+
+        assert_equal(
+            [(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)].map(Point::from),
+            Point::new(0, 0).line_to(Point::new(5, 2))
+        );
+
+        assert_equal([Point::new(4, 4)], Point::new(4, 4).line_to(Point::new(4, 4)));
+    }
+
+    #[test]
+    fn point_distance_squared() {
+        assert_eq!(25, Point::new(1, 1).distance_squared(Point::new(4, 5)));
+        assert_eq!(25u32, Point::new(4, 5).distance_squared(Point::new(1, 1)));
+        assert_eq!(0, Point::new(-2, 3).distance_squared(Point::new(-2, 3)));
+    }
+
+    #[test]
+    fn point_closest_to() {
+        let candidates = [(5, 5), (-1, 2), (2, -1), (0, 3)].map(Point::from);
+
+        assert_eq!(Some(Point::new(-1, 2)), Point::zero().closest_to(candidates));
+        assert_eq!(Some(Point::new(5, 5)), Point::new(4, 4).closest_to(candidates));
+        assert_eq!(None, Point::<i32>::zero().closest_to([]));
+    }
+
+    #[test]
+    fn point_sum() {
+        assert_eq!(7, Point::new(3, 4).sum());
+        assert_eq!(-1, Point::new(3, -4).sum());
+    }
+
+    #[test]
+    fn point_signum() {
+        assert_eq!(Point::new(1, -1), Point::new(7, -3).signum());
+        assert_eq!(Point::new(0, 1), Point::<u8>::new(0, 5).signum());
+    }
+
+    #[test]
+    fn point_straight_line_to() {
+        let line = |from: (i32, i32), to: (i32, i32)| Point::from(from)
+            .straight_line_to(Point::from(to))
+            .map(Vec::from_iter);
+
+        assert_eq!(Some([(1, 1), (2, 1), (3, 1)].map(Point::from).to_vec()), line((1, 1), (3, 1)));
+        assert_eq!(Some([(0, 2), (0, 1), (0, 0)].map(Point::from).to_vec()), line((0, 2), (0, 0)));
+        assert_eq!(Some([(1, 1), (2, 0), (3, -1)].map(Point::from).to_vec()), line((1, 1), (3, -1)));
+        assert_eq!(Some(vec![Point::new(5, 5)]), line((5, 5), (5, 5)));
+        assert_eq!(None, line((0, 0), (2, 1)));
+    }
+
+    #[test]
+    fn point_manhattan_ring() {
+        let center = Point::new(2, -1);
+
+        assert_equal([center], center.manhattan_ring(0));
+        assert_eq!(0, center.manhattan_ring(-1).count());
+
+        for radius in 1..5 {
+            let ring: HashSet<_> = center.manhattan_ring(radius).collect();
+            assert_eq!(4 * radius, i32::try_from(ring.len()).unwrap());
+            assert!(ring.iter().all(|&point| point.manhattan_distance(center) == radius));
+        }
+    }
+
+    #[test]
+    fn point_step() {
+        assert_eq!(None, Point::<u32>::zero().step(direction::Cardinal::North));
+        assert_eq!(Some(Point::new(0, -1)), Point::<i32>::zero().step(direction::Cardinal::North));
+        assert_eq!(Some(Point::new(3, 1)), Point::<u8>::new(2, 2).step(direction::Ordinal::NorthEast));
     }
 }
\ No newline at end of file
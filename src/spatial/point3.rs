@@ -0,0 +1,233 @@
+use std::cmp::minmax;
+use std::ops::{Add, Sub};
+
+use nom::Parser;
+use nom::character::complete::char;
+use nom::sequence::preceded;
+use itertools::iproduct;
+use num_traits::{One, Signed, Zero};
+
+use crate::num::CheckedAddSigned;
+use crate::parsing::{Parsable, ParsingResult};
+
+/// Represents a point in 3D space
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default,
+    derive_more::Add, derive_more::Sub, derive_more::Neg,
+    derive_more::Display
+)]
+#[display("({}, {}, {})", x, y, z)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+impl<T> Point3<T> {
+    /// Creates a new [`Point3`] with the corresponding `x`, `y` and `z` components
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts from [`Point3<T>`] to [`Point3<U>`]
+    /// 
+    /// Returns [`None`] if the conversion is not possible
+    pub fn cast<U>(self) -> Option<Point3<U>> where
+        T: TryInto<U>
+    {
+        Some(Point3 {
+            x: self.x.try_into().ok()?,
+            y: self.y.try_into().ok()?,
+            z: self.z.try_into().ok()?
+        })
+    }
+
+    /// Attempts to add a signed [`Point3<U>`] to `self`,
+    /// 
+    /// returns [`None`] if the result is not a valid `T`
+    pub fn add_signed<U>(self, rhs: U) -> Option<Self> where
+        T: CheckedAddSigned,
+        U: Into<Point3<T::Signed>>
+    {
+        let Self { x, y, z } = self;
+        let Point3 { x: dx, y: dy, z: dz } = rhs.into();
+
+        Some(Self {
+            x: x.checked_add_signed(dx)?,
+            y: y.checked_add_signed(dy)?,
+            z: z.checked_add_signed(dz)?
+        })
+    }
+
+    /// Creates an iterator over the 6 face-adjacent neighbours of `self`
+    /// which are representable by `T`
+    pub fn neighbours6(self) -> impl Iterator<Item=Self> where
+        T: Copy + CheckedAddSigned
+    {
+        Direction3::all().filter_map(move |direction| self.add_signed(direction.vector()))
+    }
+
+    /// Creates an iterator over all 26 surrounding neighbours of `self`
+    /// which are representable by `T`
+    pub fn neighbours26(self) -> impl Iterator<Item=Self> where
+        T: Copy + CheckedAddSigned,
+        T::Signed: Copy
+    {
+        let offsets = [-T::Signed::one(), T::Signed::zero(), T::Signed::one()];
+
+        iproduct!(offsets, offsets, offsets)
+            .filter(|&(dx, dy, dz)| !(dx.is_zero() && dy.is_zero() && dz.is_zero()))
+            .filter_map(move |offset| self.add_signed(offset))
+    }
+
+    #[must_use]
+    /// Returns the `(0, 0, 0)` (origin) [`Point3`]
+    pub fn zero() -> Self where
+        T: Zero
+    {
+        Self { x: T::zero(), y: T::zero(), z: T::zero() }
+    }
+
+    #[must_use]
+    /// Returns the `(1, 1, 1)` (unit) [`Point3`]
+    pub fn one() -> Self where
+        T: One
+    {
+        Self { x: T::one(), y: T::one(), z: T::one() }
+    }
+
+    #[must_use]
+    /// Computes the manhatten distance of `self` to `other`
+    /// 
+    /// The manhattan distance is the sum of the absolute differences
+    /// of the components of the points
+    pub fn manhattan_distance(self, other: Self) -> T where
+        T: Ord + Sub<Output=T> + Add<Output=T>
+    {
+        let [min_x, max_x] = minmax(self.x, other.x);
+        let [min_y, max_y] = minmax(self.y, other.y);
+        let [min_z, max_z] = minmax(self.z, other.z);
+
+        max_x - min_x + max_y - min_y + max_z - min_z
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(val: Point3<T>) -> Self {
+        (val.x, val.y, val.z)
+    }
+}
+
+/// The six directions along the axes of 3D space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction3 {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ
+}
+
+impl Direction3 {
+    /// Obtain the direction vector associated with a given direction
+    #[must_use]
+    pub fn vector<T: Signed>(self) -> (T, T, T) {
+        match self {
+            Self::PositiveX => (T::one(), T::zero(), T::zero()),
+            Self::NegativeX => (-T::one(), T::zero(), T::zero()),
+            Self::PositiveY => (T::zero(), T::one(), T::zero()),
+            Self::NegativeY => (T::zero(), -T::one(), T::zero()),
+            Self::PositiveZ => (T::zero(), T::zero(), T::one()),
+            Self::NegativeZ => (T::zero(), T::zero(), -T::one())
+        }
+    }
+
+    /// Invert the direction to get the direction opposite of it
+    #[must_use]
+    pub const fn inverted(self) -> Self {
+        match self {
+            Self::PositiveX => Self::NegativeX,
+            Self::NegativeX => Self::PositiveX,
+            Self::PositiveY => Self::NegativeY,
+            Self::NegativeY => Self::PositiveY,
+            Self::PositiveZ => Self::NegativeZ,
+            Self::NegativeZ => Self::PositiveZ
+        }
+    }
+
+    /// Creates an iterator over all six directions
+    #[must_use]
+    pub fn all() -> impl ExactSizeIterator<Item=Self> {
+        [
+            Self::PositiveX,
+            Self::NegativeX,
+            Self::PositiveY,
+            Self::NegativeY,
+            Self::PositiveZ,
+            Self::NegativeZ
+        ].into_iter()
+    }
+}
+
+impl<'a, T> Parsable<'a> for Point3<T> where
+    T: Parsable<'a>
+{
+    fn parse(input: &'a str) -> ParsingResult<'a, Self> {
+        (T::parse, preceded(char(','), T::parse), preceded(char(','), T::parse))
+            .map(Self::from)
+            .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsing::parse;
+    use super::*;
+
+    #[test]
+    fn point3_new() {
+        assert_eq!(Point3 { x: 1, y: 2, z: 3 }, Point3::new(1, 2, 3));
+        assert_eq!(Point3::new(0, 0, 0), Point3::zero());
+        assert_eq!(Point3::new(2, 0, -2), Point3::new(1, 2, 3) + Point3::one() - Point3::new(0, 3, 6));
+        assert_eq!(Point3::new(-1, 2, -3), -Point3::new(1, -2, 3));
+    }
+
+    #[test]
+    fn point3_manhattan_distance() {
+        assert_eq!(12, Point3::new(5, 2, 0).manhattan_distance(Point3::new(1, -2, 4)));
+        assert_eq!(0, Point3::one().manhattan_distance(Point3::one()));
+    }
+
+    #[test]
+    fn point3_parse() {
+        assert_eq!(Point3::new(1, 2, 3), parse::<Point3<u32>>("1,2,3").unwrap());
+        assert_eq!(Point3::new(-1, 0, 7), parse::<Point3<i32>>("-1,0,7").unwrap());
+        assert!(parse::<Point3<u32>>("1,2").is_err());
+    }
+
+    #[test]
+    fn point3_cast() {
+        assert_eq!(Some(Point3::<usize>::new(1, 0, 2)), Point3::<isize>::new(1, 0, 2).cast());
+        assert_eq!(None, Point3::<i32>::new(1, -1, 0).cast::<u32>());
+    }
+
+    #[test]
+    fn point3_neighbours() {
+        let point = Point3::<i32>::new(1, 1, 1);
+
+        assert_eq!(6, point.neighbours6().count());
+        assert_eq!(26, point.neighbours26().count());
+        assert!(point.neighbours26().all(|neighbour| neighbour != point));
+        assert!(point.neighbours6().all(|neighbour| neighbour.manhattan_distance(point) == 1));
+
+        assert_eq!(3, Point3::<u32>::zero().neighbours6().count());
+        assert_eq!(7, Point3::<u32>::zero().neighbours26().count());
+    }
+}
\ No newline at end of file
@@ -7,6 +7,9 @@ pub fn snd<T, U>((_, snd): (T, U)) -> U { snd }
 /// Swaps the two elements of a tuple
 pub fn swap<T, U>((fst, snd): (T, U)) -> (U, T) { (snd, fst) }
 
+/// Applies `f` to both elements of a homogeneous tuple
+pub fn map_both<T, U, F: Fn(T) -> U>((fst, snd): (T, T), f: F) -> (U, U) { (f(fst), f(snd)) }
+
 /// Trait for retrieving the first element of a tuple
 pub trait Fst {
     type Output;
@@ -23,6 +26,14 @@ pub trait Snd {
     fn snd(self) -> Self::Output;
 }
 
+/// Trait for retrieving the third element of a tuple
+pub trait Thd {
+    type Output;
+
+    /// Returns the third element of a tuple
+    fn thd(self) -> Self::Output;
+}
+
 /// Trait for swapping the two elements of a tuple
 pub trait Swap {
     type Output;
@@ -31,6 +42,28 @@ pub trait Swap {
     fn swap(self) -> Self::Output;
 }
 
+/// Trait for transforming the first element of a tuple
+pub trait MapFst<T, V> {
+    type Output;
+
+    /// Applies `f` to the first element of a tuple, leaving the other untouched
+    fn map_fst<F: FnOnce(T) -> V>(self, f: F) -> Self::Output;
+}
+
+/// Trait for transforming the second element of a tuple
+pub trait MapSnd<U, V> {
+    type Output;
+
+    /// Applies `f` to the second element of a tuple, leaving the other untouched
+    fn map_snd<F: FnOnce(U) -> V>(self, f: F) -> Self::Output;
+}
+
+/// Trait for transforming both elements of a homogeneous tuple
+pub trait MapBoth<T> {
+    /// Applies `f` to both elements of a tuple
+    fn map_both<U, F: Fn(T) -> U>(self, f: F) -> (U, U);
+}
+
 impl<T, U> Fst for (T, U) {
     type Output = T;
     
@@ -47,10 +80,86 @@ impl<T, U> Snd for (T, U) {
     }
 }
 
+impl<T, U, V> Fst for (T, U, V) {
+    type Output = T;
+
+    fn fst(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl<T, U, V> Snd for (T, U, V) {
+    type Output = U;
+
+    fn snd(self) -> Self::Output {
+        self.1
+    }
+}
+
+impl<T, U, V> Thd for (T, U, V) {
+    type Output = V;
+
+    fn thd(self) -> Self::Output {
+        self.2
+    }
+}
+
 impl<T, U> Swap for (T, U) {
     type Output = (U, T);
 
     fn swap(self) -> Self::Output {
         (self.1, self.0)
     }
+}
+
+impl<T, U, V> MapFst<T, V> for (T, U) {
+    type Output = (V, U);
+
+    fn map_fst<F: FnOnce(T) -> V>(self, f: F) -> Self::Output {
+        (f(self.0), self.1)
+    }
+}
+
+impl<T, U, V> MapSnd<U, V> for (T, U) {
+    type Output = (T, V);
+
+    fn map_snd<F: FnOnce(U) -> V>(self, f: F) -> Self::Output {
+        (self.0, f(self.1))
+    }
+}
+
+impl<T> MapBoth<T> for (T, T) {
+    fn map_both<U, F: Fn(T) -> U>(self, f: F) -> (U, U) {
+        map_both(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_fst_snd() {
+        assert_eq!((2, "a"), (1, "a").map_fst(|x| x + 1));
+        assert_eq!((1, 1), (1, "a").map_snd(str::len));
+        assert_eq!(
+            vec![("a", 2), ("b", 4)],
+            [("a", 1), ("b", 2)].into_iter().map(|pair| pair.map_snd(|x| x * 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn triple_accessors() {
+        let triple = (1, "b", 'c');
+
+        assert_eq!(1, triple.fst());
+        assert_eq!("b", triple.snd());
+        assert_eq!('c', triple.thd());
+    }
+
+    #[test]
+    fn map_both_elements() {
+        assert_eq!((6, 8), (3, 4).map_both(|x| x * 2));
+        assert_eq!((6, 8), map_both((3, 4), |x| x * 2));
+    }
 }
\ No newline at end of file
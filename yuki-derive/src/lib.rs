@@ -0,0 +1,72 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Expr, ExprLit, Fields, Lit, LitStr, Meta};
+
+/// Derives `Parsable` for an enum of unit variants,
+/// where every variant is annotated with one or more `#[token = "..."]` attributes
+///
+/// Longer tokens are attempted first, so a token that is a prefix
+/// of another token does not prevent the longer one from matching
+#[proc_macro_derive(Parsable, attributes(token))]
+pub fn derive_parsable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(input, "Parsable can only be derived for enums"));
+    };
+
+    let mut tokens = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(variant, "Parsable can only be derived for unit variants"));
+        }
+
+        let variant_tokens = variant.attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("token"))
+            .map(|attribute| token(&attribute.meta))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if variant_tokens.is_empty() {
+            return Err(Error::new_spanned(variant, "Expected at least one #[token = \"...\"] attribute"));
+        }
+
+        tokens.extend(variant_tokens.into_iter().map(|token| (token, &variant.ident)));
+    }
+
+    tokens.sort_by_key(|(token, _)| std::cmp::Reverse(token.value().len()));
+
+    let name = &input.ident;
+    let (token, variant): (Vec<_>, Vec<_>) = tokens.into_iter().unzip();
+
+    Ok(quote! {
+        impl<'a> ::yuki::parsing::Parsable<'a> for #name {
+            fn parse(input: &'a str) -> ::yuki::parsing::ParsingResult<'a, Self> {
+                #(
+                    if let Some(remaining) = input.strip_prefix(#token) {
+                        return Ok((remaining, Self::#variant));
+                    }
+                )*
+
+                Err(::yuki::parsing::token_error(input))
+            }
+        }
+    })
+}
+
+fn token(meta: &Meta) -> Result<LitStr, Error> {
+    if let Meta::NameValue(meta) = meta
+        && let Expr::Lit(ExprLit { lit: Lit::Str(token), .. }) = &meta.value
+    {
+        return Ok(token.clone());
+    }
+
+    Err(Error::new_spanned(meta, "Expected #[token = \"...\"]"))
+}
\ No newline at end of file